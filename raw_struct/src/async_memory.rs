@@ -0,0 +1,274 @@
+use core::{
+    future::Future,
+    marker,
+    mem::{
+        self,
+        MaybeUninit,
+    },
+    slice,
+};
+
+use crate::{
+    builtins::{
+        Pointer,
+        PointerPolicy,
+        Ptr64,
+    },
+    memory::FromMemoryView,
+    Copy,
+    CopyConstructable,
+    MemoryDecodeError,
+    MemoryView,
+    MemoryViewDereferenceable,
+    Reference,
+    TypedViewableField,
+    Viewable,
+    ViewableExtends,
+    ViewableField,
+    ViewableSized,
+};
+
+/// Asynchronous counterpart of [`MemoryView`].
+///
+/// No runtime is required by this crate.
+///
+/// Note:
+/// Generic code can not require the returned futures to be `Send`, as the futures of the trait methods
+/// are opaque. If a `Send` future is required (e.g. for spawning it on a multi threaded runtime),
+/// the concrete memory view type must be used.
+pub trait AsyncMemoryView {
+    type AccessError;
+
+    fn read_memory(
+        &self,
+        offset: u64,
+        buffer: &mut [u8],
+    ) -> impl Future<Output = Result<(), Self::AccessError>>;
+}
+
+impl<M: ?Sized + AsyncMemoryView> AsyncMemoryView for &M {
+    type AccessError = M::AccessError;
+
+    fn read_memory(
+        &self,
+        offset: u64,
+        buffer: &mut [u8],
+    ) -> impl Future<Output = Result<(), Self::AccessError>> {
+        M::read_memory(self, offset, buffer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M: ?Sized + AsyncMemoryView> AsyncMemoryView for alloc::sync::Arc<M> {
+    type AccessError = M::AccessError;
+
+    fn read_memory(
+        &self,
+        offset: u64,
+        buffer: &mut [u8],
+    ) -> impl Future<Output = Result<(), Self::AccessError>> {
+        M::read_memory(self, offset, buffer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M: ?Sized + AsyncMemoryView> AsyncMemoryView for alloc::rc::Rc<M> {
+    type AccessError = M::AccessError;
+
+    fn read_memory(
+        &self,
+        offset: u64,
+        buffer: &mut [u8],
+    ) -> impl Future<Output = Result<(), Self::AccessError>> {
+        M::read_memory(self, offset, buffer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M: ?Sized + AsyncMemoryView> AsyncMemoryView for alloc::boxed::Box<M> {
+    type AccessError = M::AccessError;
+
+    fn read_memory(
+        &self,
+        offset: u64,
+        buffer: &mut [u8],
+    ) -> impl Future<Output = Result<(), Self::AccessError>> {
+        M::read_memory(self, offset, buffer)
+    }
+}
+
+/// Asynchronous counterpart of [`MemoryViewDereferenceable`].
+pub trait AsyncMemoryViewDereferenceable: AsyncMemoryView {
+    fn dereference(&self, address: u64) -> impl Future<Output = Result<u64, Self::AccessError>>;
+}
+
+impl<M: ?Sized + AsyncMemoryViewDereferenceable> AsyncMemoryViewDereferenceable for &M {
+    fn dereference(&self, address: u64) -> impl Future<Output = Result<u64, Self::AccessError>> {
+        M::dereference(self, address)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M: ?Sized + AsyncMemoryViewDereferenceable> AsyncMemoryViewDereferenceable
+    for alloc::sync::Arc<M>
+{
+    fn dereference(&self, address: u64) -> impl Future<Output = Result<u64, Self::AccessError>> {
+        M::dereference(self, address)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M: ?Sized + AsyncMemoryViewDereferenceable> AsyncMemoryViewDereferenceable
+    for alloc::rc::Rc<M>
+{
+    fn dereference(&self, address: u64) -> impl Future<Output = Result<u64, Self::AccessError>> {
+        M::dereference(self, address)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M: ?Sized + AsyncMemoryViewDereferenceable> AsyncMemoryViewDereferenceable
+    for alloc::boxed::Box<M>
+{
+    fn dereference(&self, address: u64) -> impl Future<Output = Result<u64, Self::AccessError>> {
+        M::dereference(self, address)
+    }
+}
+
+/// Exposes a synchronous [`MemoryView`] as [`AsyncMemoryView`].
+/// All reads complete immediately.
+#[derive(Debug, Clone, marker::Copy)]
+pub struct AsyncAdapter<M>(pub M);
+
+impl<M: MemoryView> AsyncMemoryView for AsyncAdapter<M> {
+    type AccessError = M::AccessError;
+
+    async fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        self.0.read_memory(offset, buffer)
+    }
+}
+
+impl<M: MemoryViewDereferenceable> AsyncMemoryViewDereferenceable for AsyncAdapter<M> {
+    async fn dereference(&self, address: u64) -> Result<u64, Self::AccessError> {
+        self.0.dereference(address)
+    }
+}
+
+/// Asynchronous counterpart of [`FromMemoryView`].
+pub trait AsyncFromMemoryView: FromMemoryView {
+    fn read_object_async<M: AsyncMemoryView>(
+        view: &M,
+        offset: u64,
+    ) -> impl Future<Output = Result<Self, MemoryDecodeError<M::AccessError, Self::DecodeError>>>;
+}
+
+async fn read_copy_async<T: CopyConstructable, M: AsyncMemoryView>(
+    memory: &M,
+    offset: u64,
+) -> Result<T, M::AccessError> {
+    let mut result = MaybeUninit::<T>::uninit();
+
+    let result_memory =
+        unsafe { slice::from_raw_parts_mut(result.as_mut_ptr() as *mut u8, mem::size_of::<T>()) };
+    memory.read_memory(offset, result_memory).await?;
+
+    Ok(unsafe { result.assume_init() })
+}
+
+impl<T: CopyConstructable> AsyncFromMemoryView for T {
+    async fn read_object_async<M: AsyncMemoryView>(
+        view: &M,
+        offset: u64,
+    ) -> Result<Self, MemoryDecodeError<M::AccessError, Self::DecodeError>> {
+        read_copy_async(view, offset)
+            .await
+            .map_err(MemoryDecodeError::MemoryAccess)
+    }
+}
+
+impl AsyncFromMemoryView for bool {
+    async fn read_object_async<M: AsyncMemoryView>(
+        view: &M,
+        offset: u64,
+    ) -> Result<Self, MemoryDecodeError<M::AccessError, Self::DecodeError>> {
+        let value = u8::read_object_async(view, offset).await?;
+        Ok(value > 0)
+    }
+}
+
+impl<V: ViewableSized> AsyncFromMemoryView for Copy<V> {
+    async fn read_object_async<M: AsyncMemoryView>(
+        view: &M,
+        offset: u64,
+    ) -> Result<Self, MemoryDecodeError<M::AccessError, Self::DecodeError>> {
+        Ok(Self::new(V::Memory::read_object_async(view, offset).await?))
+    }
+}
+
+impl<T: Viewable, M: AsyncMemoryView> Reference<T, M> {
+    /// Asynchronous counterpart of [`Reference::read_field`].
+    pub async fn read_field_async<R: AsyncFromMemoryView, C>(
+        &self,
+        field: &TypedViewableField<C, R>,
+    ) -> Result<R, MemoryDecodeError<M::AccessError, R::DecodeError>>
+    where
+        T: ViewableExtends<C>,
+    {
+        R::read_object_async(self.memory(), self.memory_address() + field.offset()).await
+    }
+}
+
+impl<T: Viewable, M: AsyncMemoryViewDereferenceable> Reference<T, M> {
+    /// Asynchronous counterpart of [`Reference::dereference_field`].
+    pub async fn dereference_field_async<R: Pointer, C>(
+        &self,
+        field: &TypedViewableField<C, R>,
+    ) -> Result<Reference<R::Target, &M>, M::AccessError>
+    where
+        T: ViewableExtends<C>,
+    {
+        let ptr_value = self
+            .read_field_async(field)
+            .await
+            .map_err(|err| err.into_access_error())?;
+        let memory_offset = self
+            .memory()
+            .dereference(ptr_value.target_address())
+            .await?;
+        Ok(Reference::new(self.memory(), memory_offset))
+    }
+}
+
+impl<T: ViewableSized, M: AsyncMemoryView> Reference<T, M> {
+    /// Asynchronous counterpart of [`Reference::create_copy`].
+    pub async fn create_copy_async(&self) -> Result<Copy<T>, M::AccessError> {
+        Copy::read_from_memory_async(self.memory(), self.memory_address()).await
+    }
+}
+
+impl<V: ViewableSized> Copy<V> {
+    /// Asynchronous counterpart of [`Copy::read_from_memory`].
+    pub async fn read_from_memory_async<M: AsyncMemoryView>(
+        memory: &M,
+        offset: u64,
+    ) -> Result<Self, M::AccessError> {
+        Ok(Self::new(read_copy_async(memory, offset).await?))
+    }
+}
+
+impl<T: AsyncFromMemoryView, P: PointerPolicy> Ptr64<T, P> {
+    /// Asynchronous counterpart of [`Ptr64::read_value`].
+    #[must_use = "copied result must be used"]
+    pub async fn read_value_async<M: AsyncMemoryView>(
+        &self,
+        memory: &M,
+    ) -> Result<Option<T>, MemoryDecodeError<M::AccessError, T::DecodeError>> {
+        if self.is_resolved_null() {
+            return Ok(None);
+        }
+
+        T::read_object_async(memory, self.resolved_address())
+            .await
+            .map(Some)
+    }
+}
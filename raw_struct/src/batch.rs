@@ -0,0 +1,158 @@
+use core::{
+    convert::Infallible,
+    mem::{
+        self,
+        MaybeUninit,
+    },
+    slice,
+};
+
+use crate::{
+    FromMemoryView,
+    MemoryDecodeError,
+    MemoryView,
+    TypedViewableField,
+    ViewableExtends,
+    ViewableField,
+};
+
+/// A set of fields of `T` which can be read with a single batched memory read.
+/// Implemented for tuples of [`TypedViewableField`]s with infallible [`FromMemoryView`] values.
+/// Values without a known [`FromMemoryView::object_size`] are read separately after the batch.
+///
+/// See [`Reference::read_fields`](crate::Reference::read_fields).
+pub trait FieldBatch<T> {
+    type Values;
+
+    fn read_batch<M: MemoryView>(
+        &self,
+        memory: &M,
+        address: u64,
+    ) -> Result<Self::Values, M::AccessError>;
+}
+
+/// Number of bytes of `R` read within the batch.
+/// The bytes are stored within the memory of `R` and therefore must not exceed its size.
+fn batch_size<R: FromMemoryView>() -> usize {
+    R::object_size()
+        .filter(|size| *size <= mem::size_of::<R>())
+        .unwrap_or(0)
+}
+
+fn batch_buffer<R: FromMemoryView>(buffer: &mut MaybeUninit<R>) -> &mut [u8] {
+    /* the buffer is zero initialized */
+    unsafe { slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, batch_size::<R>()) }
+}
+
+/// Decode the value from the bytes read within the batch.
+/// Values which could not be read within the batch are read from the memory.
+fn decode_value<R: FromMemoryView<DecodeError = Infallible>, M: MemoryView>(
+    memory: &M,
+    address: u64,
+    buffer: &MaybeUninit<R>,
+) -> Result<R, M::AccessError> {
+    let bytes = unsafe { slice::from_raw_parts(buffer.as_ptr() as *const u8, batch_size::<R>()) };
+
+    match R::read_object(&bytes, 0x00) {
+        Ok(value) => Ok(value),
+        Err(_) => R::read_object(memory, address).map_err(MemoryDecodeError::into_access_error),
+    }
+}
+
+macro_rules! impl_field_batch {
+    ($(($field:ident, $value:ident, $C:ident, $R:ident)),+) => {
+        impl<T, $($C, $R: FromMemoryView<DecodeError = Infallible>,)+> FieldBatch<T> for ($(&TypedViewableField<$C, $R>,)+)
+        where
+            $(T: ViewableExtends<$C>,)+
+        {
+            type Values = ($($R,)+);
+
+            fn read_batch<M: MemoryView>(
+                &self,
+                memory: &M,
+                address: u64,
+            ) -> Result<Self::Values, M::AccessError> {
+                let ($($field,)+) = self;
+                let mut buffers = ($(MaybeUninit::<$R>::zeroed(),)+);
+                let ($($value,)+) = &mut buffers;
+
+                memory.read_memory_batch(&mut [$(
+                    (address + $field.offset(), batch_buffer($value)),
+                )+])?;
+
+                Ok(($(decode_value(memory, address + $field.offset(), $value)?,)+))
+            }
+        }
+    };
+}
+
+impl_field_batch!((field_1, value_1, C1, R1));
+impl_field_batch!((field_1, value_1, C1, R1), (field_2, value_2, C2, R2));
+impl_field_batch!(
+    (field_1, value_1, C1, R1),
+    (field_2, value_2, C2, R2),
+    (field_3, value_3, C3, R3)
+);
+impl_field_batch!(
+    (field_1, value_1, C1, R1),
+    (field_2, value_2, C2, R2),
+    (field_3, value_3, C3, R3),
+    (field_4, value_4, C4, R4)
+);
+impl_field_batch!(
+    (field_1, value_1, C1, R1),
+    (field_2, value_2, C2, R2),
+    (field_3, value_3, C3, R3),
+    (field_4, value_4, C4, R4),
+    (field_5, value_5, C5, R5)
+);
+impl_field_batch!(
+    (field_1, value_1, C1, R1),
+    (field_2, value_2, C2, R2),
+    (field_3, value_3, C3, R3),
+    (field_4, value_4, C4, R4),
+    (field_5, value_5, C5, R5),
+    (field_6, value_6, C6, R6)
+);
+impl_field_batch!(
+    (field_1, value_1, C1, R1),
+    (field_2, value_2, C2, R2),
+    (field_3, value_3, C3, R3),
+    (field_4, value_4, C4, R4),
+    (field_5, value_5, C5, R5),
+    (field_6, value_6, C6, R6),
+    (field_7, value_7, C7, R7)
+);
+impl_field_batch!(
+    (field_1, value_1, C1, R1),
+    (field_2, value_2, C2, R2),
+    (field_3, value_3, C3, R3),
+    (field_4, value_4, C4, R4),
+    (field_5, value_5, C5, R5),
+    (field_6, value_6, C6, R6),
+    (field_7, value_7, C7, R7),
+    (field_8, value_8, C8, R8)
+);
+impl_field_batch!(
+    (field_1, value_1, C1, R1),
+    (field_2, value_2, C2, R2),
+    (field_3, value_3, C3, R3),
+    (field_4, value_4, C4, R4),
+    (field_5, value_5, C5, R5),
+    (field_6, value_6, C6, R6),
+    (field_7, value_7, C7, R7),
+    (field_8, value_8, C8, R8),
+    (field_9, value_9, C9, R9)
+);
+impl_field_batch!(
+    (field_1, value_1, C1, R1),
+    (field_2, value_2, C2, R2),
+    (field_3, value_3, C3, R3),
+    (field_4, value_4, C4, R4),
+    (field_5, value_5, C5, R5),
+    (field_6, value_6, C6, R6),
+    (field_7, value_7, C7, R7),
+    (field_8, value_8, C8, R8),
+    (field_9, value_9, C9, R9),
+    (field_10, value_10, C10, R10)
+);
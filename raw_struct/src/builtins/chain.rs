@@ -0,0 +1,175 @@
+use core::{
+    convert::Infallible,
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+};
+
+use crate::{
+    FromMemoryView,
+    MemoryDecodeError,
+    MemoryViewDereferenceable,
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum PtrChainError<A, V> {
+    /// Accessing `address` during hop `hop` failed.
+    /// Hop `n` reads the n-th pointer of the chain, the last hop reads the value itself.
+    MemoryAccess { hop: usize, address: u64, error: A },
+
+    /// The pointer read from `address` during hop `hop` is null.
+    NullPointer { hop: usize, address: u64 },
+
+    /// The final value could not be decoded.
+    ValueDecode(V),
+}
+
+impl<A> PtrChainError<A, Infallible> {
+    fn cast<V>(self) -> PtrChainError<A, V> {
+        match self {
+            Self::MemoryAccess {
+                hop,
+                address,
+                error,
+            } => PtrChainError::MemoryAccess {
+                hop,
+                address,
+                error,
+            },
+            Self::NullPointer { hop, address } => PtrChainError::NullPointer { hop, address },
+            Self::ValueDecode(_) => unreachable!(),
+        }
+    }
+}
+
+impl<A: Display, V: Display> fmt::Display for PtrChainError<A, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MemoryAccess {
+                hop,
+                address,
+                error,
+            } => write!(f, "hop {} failed to access 0x{:X}: {}", hop, address, error),
+            Self::NullPointer { hop, address } => {
+                write!(f, "hop {} read a null pointer at 0x{:X}", hop, address)
+            }
+            Self::ValueDecode(inner) => inner.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A: Display + Debug, V: Display + Debug> std::error::Error for PtrChainError<A, V> {}
+
+#[cfg(not(feature = "std"))]
+impl<A: Display + Debug, V: Display + Debug> core::error::Error for PtrChainError<A, V> {}
+
+/// A multi level pointer path.
+///
+/// Starting at a base address, every but the last offset is added to the current address
+/// and the pointer at the resulting address is followed. The last offset points to the value itself.
+/// `[0x10, 0x28, 0x08]` evaluates as `*(*(*(base + 0x10) + 0x28) + 0x08)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PtrChain<'a> {
+    offsets: &'a [u64],
+}
+
+impl<'a> PtrChain<'a> {
+    pub const fn new(offsets: &'a [u64]) -> Self {
+        Self { offsets }
+    }
+
+    pub const fn offsets(&self) -> &'a [u64] {
+        self.offsets
+    }
+
+    /// Follow all pointers and return the address of the value.
+    pub fn resolve<M: MemoryViewDereferenceable>(
+        &self,
+        memory: &M,
+        base: u64,
+    ) -> Result<u64, PtrChainError<M::AccessError, Infallible>> {
+        resolve_chain(memory, base, None, self.offsets)
+    }
+
+    /// Follow all pointers and read the value.
+    pub fn read<T: FromMemoryView, M: MemoryViewDereferenceable>(
+        &self,
+        memory: &M,
+        base: u64,
+    ) -> Result<T, PtrChainError<M::AccessError, T::DecodeError>> {
+        read_chain(memory, base, None, self.offsets)
+    }
+}
+
+/// Type of the pointers followed by a pointer chain.
+pub(crate) type ChainPointer = u64;
+
+/// Evaluate the chain `leading` followed by `offsets`.
+pub(crate) fn resolve_chain<M: MemoryViewDereferenceable>(
+    memory: &M,
+    base: u64,
+    leading: Option<u64>,
+    offsets: &[u64],
+) -> Result<u64, PtrChainError<M::AccessError, Infallible>> {
+    let pointer_count = chain_len(leading, offsets).saturating_sub(1);
+
+    let mut address = base;
+    for (hop, offset) in leading
+        .into_iter()
+        .chain(offsets.iter().copied())
+        .enumerate()
+    {
+        address = address.wrapping_add(offset);
+        if hop == pointer_count {
+            break;
+        }
+
+        let pointer = ChainPointer::read_object(memory, address).map_err(|err| {
+            PtrChainError::MemoryAccess {
+                hop,
+                address,
+                error: err.into_access_error(),
+            }
+        })?;
+
+        if pointer == 0 {
+            return Err(PtrChainError::NullPointer { hop, address });
+        }
+
+        address = memory
+            .dereference(pointer)
+            .map_err(|error| PtrChainError::MemoryAccess {
+                hop,
+                address: pointer,
+                error,
+            })?;
+    }
+
+    Ok(address)
+}
+
+pub(crate) fn read_chain<T: FromMemoryView, M: MemoryViewDereferenceable>(
+    memory: &M,
+    base: u64,
+    leading: Option<u64>,
+    offsets: &[u64],
+) -> Result<T, PtrChainError<M::AccessError, T::DecodeError>> {
+    let hop = chain_len(leading, offsets).saturating_sub(1);
+    let address = resolve_chain(memory, base, leading, offsets).map_err(PtrChainError::cast)?;
+
+    T::read_object(memory, address).map_err(|err| match err {
+        MemoryDecodeError::MemoryAccess(error) => PtrChainError::MemoryAccess {
+            hop,
+            address,
+            error,
+        },
+        MemoryDecodeError::ValueDecode(error) => PtrChainError::ValueDecode(error),
+    })
+}
+
+fn chain_len(leading: Option<u64>, offsets: &[u64]) -> usize {
+    leading.iter().len() + offsets.len()
+}
@@ -0,0 +1,212 @@
+use core::marker::{
+    self,
+    PhantomData,
+};
+
+use crate::{
+    builtins::Pointer,
+    Copy,
+    CopyConstructable,
+    FromMemoryView,
+    MemoryDecodeError,
+    MemoryView,
+    PaddingFree,
+    Reference,
+    Viewable,
+    ViewableSized,
+};
+
+/// Decompression context of [`CompressedPtr32`].
+/// A compressed pointer value is decompressed as `base + (value << shift)`.
+///
+/// Contexts implementing [`Default`] (e.g. [`ConstCompression`]) are constructed on demand.
+/// Runtime contexts (e.g. one per V8 isolate) are passed explicitly:
+/// ```rust
+/// # use raw_struct::builtins::{CompressedPtr32, PointerCompression};
+/// struct Isolate {
+///     heap_base: u64,
+/// }
+///
+/// impl PointerCompression for Isolate {
+///     fn base(&self) -> u64 {
+///         self.heap_base
+///     }
+/// }
+///
+/// let isolate_a = Isolate { heap_base: 0x3A_0000_0000 };
+/// let isolate_b = Isolate { heap_base: 0x4C_0000_0000 };
+///
+/// let ptr = CompressedPtr32::<u64, Isolate>::from_value(0x1234);
+/// assert_eq!(ptr.address_in(&isolate_a), 0x3A_0000_1234);
+/// assert_eq!(ptr.address_in(&isolate_b), 0x4C_0000_1234);
+/// ```
+pub trait PointerCompression {
+    fn base(&self) -> u64;
+
+    /// Shift of the compressed value. Shifts of 64 bits or more decompress every pointer to null.
+    fn shift(&self) -> u32 {
+        0
+    }
+}
+
+/// Type level constant [`PointerCompression`] context.
+#[derive(Debug, Default, Clone, marker::Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConstCompression<const BASE: u64, const SHIFT: u32 = 0>;
+
+impl<const BASE: u64, const SHIFT: u32> PointerCompression for ConstCompression<BASE, SHIFT> {
+    fn base(&self) -> u64 {
+        BASE
+    }
+
+    fn shift(&self) -> u32 {
+        const { assert!(SHIFT < 64, "the compression shift must be less than 64") };
+        SHIFT
+    }
+}
+
+/// A 32 bit compressed pointer to `T` (e.g. V8 compressed pointers or JVM compressed oops).
+/// The pointer value is decompressed as `base + (value << shift)` where base and shift
+/// are provided by the decompression context `B`.
+/// Methods without an explicit context require `B` to implement [`Default`].
+///
+/// A compressed value of zero is considered to be null.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CompressedPtr32<T: ?Sized, B> {
+    value: u32,
+    _type: PhantomData<T>,
+    _context: PhantomData<B>,
+}
+
+impl<T: ?Sized, B> Clone for CompressedPtr32<T, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized, B> marker::Copy for CompressedPtr32<T, B> {}
+
+impl<T: ?Sized, B> CopyConstructable for CompressedPtr32<T, B> {}
+unsafe impl<T: ?Sized, B> PaddingFree for CompressedPtr32<T, B> {}
+
+impl<T: ?Sized, B> CompressedPtr32<T, B> {
+    pub const fn from_value(value: u32) -> Self {
+        Self {
+            value,
+            _type: PhantomData {},
+            _context: PhantomData {},
+        }
+    }
+
+    /// The compressed pointer value.
+    pub const fn value(&self) -> u32 {
+        self.value
+    }
+
+    pub const fn is_null(&self) -> bool {
+        self.value == 0
+    }
+
+    pub const fn cast<V>(&self) -> CompressedPtr32<V, B> {
+        CompressedPtr32::<V, B>::from_value(self.value)
+    }
+
+    /// Decompress the pointer using the given base and shift instead of the context `B`.
+    /// Returns null if `shift` is 64 or larger.
+    pub const fn address_with(&self, base: u64, shift: u32) -> u64 {
+        match (self.value as u64).checked_shl(shift) {
+            Some(offset) => base.wrapping_add(offset),
+            None => 0,
+        }
+    }
+}
+
+impl<T: ?Sized, B: PointerCompression> CompressedPtr32<T, B> {
+    /// The decompressed address the pointer points to within `context`.
+    pub fn address_in(&self, context: &B) -> u64 {
+        self.address_with(context.base(), context.shift())
+    }
+}
+
+impl<T: ?Sized, B: PointerCompression + Default> CompressedPtr32<T, B> {
+    /// The decompressed address the pointer points to.
+    pub fn address(&self) -> u64 {
+        self.address_in(&B::default())
+    }
+}
+
+impl<T: ?Sized, B: PointerCompression + Default> Pointer for CompressedPtr32<T, B> {
+    type Target = T;
+
+    fn target_address(&self) -> u64 {
+        self.address()
+    }
+}
+
+impl<T: FromMemoryView, B: PointerCompression + Default> CompressedPtr32<T, B> {
+    /// Create a copy of the value the pointer points to
+    #[must_use = "copied result must be used"]
+    pub fn read_value<M: MemoryView>(
+        &self,
+        memory: &M,
+    ) -> Result<Option<T>, MemoryDecodeError<M::AccessError, T::DecodeError>> {
+        (!self.is_null())
+            .then(|| T::read_object(memory, self.address()))
+            .transpose()
+    }
+}
+
+impl<T: Viewable, B: PointerCompression + Default> CompressedPtr32<T, B> {
+    #[must_use]
+    pub fn reference_value<M: MemoryView>(&self, memory: M) -> Option<Reference<T, M>> {
+        (!self.is_null()).then(|| Reference::new(memory, self.address()))
+    }
+}
+
+impl<T: ViewableSized, B: PointerCompression + Default> CompressedPtr32<T, B> {
+    /// Create a copy of the value the pointer points to
+    #[must_use = "copied result must be used"]
+    pub fn copy_value<M: MemoryView>(&self, memory: &M) -> Result<Option<Copy<T>>, M::AccessError> {
+        (!self.is_null())
+            .then(|| Copy::<T>::read_from_memory(memory, self.address()))
+            .transpose()
+    }
+}
+
+impl<T: FromMemoryView, B: PointerCompression> CompressedPtr32<T, B> {
+    /// Create a copy of the value the pointer points to within `context`
+    #[must_use = "copied result must be used"]
+    pub fn read_value_in<M: MemoryView>(
+        &self,
+        context: &B,
+        memory: &M,
+    ) -> Result<Option<T>, MemoryDecodeError<M::AccessError, T::DecodeError>> {
+        (!self.is_null())
+            .then(|| T::read_object(memory, self.address_in(context)))
+            .transpose()
+    }
+}
+
+impl<T: Viewable, B: PointerCompression> CompressedPtr32<T, B> {
+    #[must_use]
+    pub fn reference_value_in<M: MemoryView>(
+        &self,
+        context: &B,
+        memory: M,
+    ) -> Option<Reference<T, M>> {
+        (!self.is_null()).then(|| Reference::new(memory, self.address_in(context)))
+    }
+}
+
+impl<T: ViewableSized, B: PointerCompression> CompressedPtr32<T, B> {
+    /// Create a copy of the value the pointer points to within `context`
+    #[must_use = "copied result must be used"]
+    pub fn copy_value_in<M: MemoryView>(
+        &self,
+        context: &B,
+        memory: &M,
+    ) -> Result<Option<Copy<T>>, M::AccessError> {
+        (!self.is_null())
+            .then(|| Copy::<T>::read_from_memory(memory, self.address_in(context)))
+            .transpose()
+    }
+}
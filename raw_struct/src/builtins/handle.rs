@@ -0,0 +1,93 @@
+use core::marker::{
+    self,
+    PhantomData,
+};
+
+use crate::{
+    CopyConstructable,
+    MemoryView,
+    PaddingFree,
+    Reference,
+};
+
+/// Resolves handles referring to objects of type `T`.
+///
+/// Handles (e.g. entity handles consisting of an index and a serial number) can not be
+/// dereferenced directly but must be looked up within a handle table.
+/// ```rust
+/// # use raw_struct::{builtins::{Handle, HandleResolver}, raw_struct, FromMemoryView, MemoryView, Reference};
+/// #[raw_struct(size = 0x08)]
+/// struct Entity {
+///     #[field(offset = 0x00)]
+///     pub health: u32,
+/// }
+///
+/// struct EntityList;
+/// impl HandleResolver<Entity> for EntityList {
+///     type Raw = u32;
+///
+///     fn resolve<M: MemoryView>(handle: u32, memory: M) -> Result<Option<Reference<Entity, M>>, M::AccessError> {
+///         const ENTITY_LIST: u64 = 0x1000;
+///
+///         let index = (handle & 0x7FFF) as u64;
+///         let entity = u64::read_object(&memory, ENTITY_LIST + index * 8)
+///             .map_err(|err| err.into_access_error())?;
+///
+///         Ok((entity > 0).then(|| Reference::new(memory, entity)))
+///     }
+/// }
+///
+/// let entity_handle = Handle::<Entity, EntityList>::from_raw(0x0001_0002);
+/// ```
+pub trait HandleResolver<T: ?Sized> {
+    /// Raw in memory representation of the handle.
+    type Raw: CopyConstructable;
+
+    /// Resolve the raw handle.
+    /// Returns `None` if the handle is invalid or the referred object does not exist anymore.
+    fn resolve<M: MemoryView>(
+        handle: Self::Raw,
+        memory: M,
+    ) -> Result<Option<Reference<T, M>>, M::AccessError>;
+}
+
+/// A handle referring to an object of type `T` which is resolved by `R`.
+pub struct Handle<T: ?Sized, R: HandleResolver<T>> {
+    raw: R::Raw,
+    _type: PhantomData<T>,
+    _resolver: PhantomData<R>,
+}
+
+impl<T: ?Sized, R: HandleResolver<T>> Clone for Handle<T, R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized, R: HandleResolver<T>> marker::Copy for Handle<T, R> {}
+
+impl<T: ?Sized, R: HandleResolver<T>> CopyConstructable for Handle<T, R> {}
+unsafe impl<T: ?Sized, R: HandleResolver<T>> PaddingFree for Handle<T, R> where R::Raw: PaddingFree {}
+
+impl<T: ?Sized, R: HandleResolver<T>> Handle<T, R> {
+    pub const fn from_raw(raw: R::Raw) -> Self {
+        Self {
+            raw,
+            _type: PhantomData {},
+            _resolver: PhantomData {},
+        }
+    }
+
+    /// The raw handle value.
+    pub fn raw(&self) -> R::Raw {
+        self.raw
+    }
+
+    /// Resolve the object the handle refers to.
+    pub fn resolve<M: MemoryView>(
+        &self,
+        memory: M,
+    ) -> Result<Option<Reference<T, M>>, M::AccessError> {
+        R::resolve(self.raw, memory)
+    }
+}
@@ -1,2 +1,31 @@
+pub(crate) mod chain;
+pub use chain::{
+    PtrChain,
+    PtrChainError,
+};
+
+mod compressed;
+pub use compressed::{
+    CompressedPtr32,
+    ConstCompression,
+    PointerCompression,
+};
+
+mod handle;
+pub use handle::{
+    Handle,
+    HandleResolver,
+};
+
 mod ptr;
-pub use ptr::Ptr64;
+pub use ptr::{
+    CanonicalAddress48,
+    MaskBits,
+    Pointer,
+    PointerPolicy,
+    Ptr64,
+    RawPointer,
+    SignExtend,
+    StripLowBits,
+    TopByteIgnore,
+};
@@ -9,70 +9,216 @@ use crate::{
     FromMemoryView,
     MemoryDecodeError,
     MemoryView,
+    PaddingFree,
     Reference,
     Viewable,
     ViewableSized,
 };
 
+/// Canonicalization policy of pointer values.
+///
+/// Pointers may carry additional information within unused address bits,
+/// e.g. AArch64 Top-Byte-Ignore / pointer authentication codes or flags stored in the low
+/// bits of aligned pointers. The policy defines how such a raw pointer value is turned into
+/// the actual address.
+pub trait PointerPolicy {
+    /// Bits of the raw pointer value which are part of the address.
+    /// All other bits are considered tag bits.
+    const ADDRESS_MASK: u64;
+
+    /// Turn a raw pointer value into the canonical address.
+    fn canonicalize(raw: u64) -> u64 {
+        raw & Self::ADDRESS_MASK
+    }
+}
+
+/// Use the raw pointer value as is.
+#[derive(Debug, Clone, marker::Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RawPointer;
+
+impl PointerPolicy for RawPointer {
+    const ADDRESS_MASK: u64 = u64::MAX;
+}
+
+/// Sign-extend the address from bit `BIT`.
+/// All bits above `BIT` are considered tag bits.
+#[derive(Debug, Clone, marker::Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SignExtend<const BIT: u32>;
+
+impl<const BIT: u32> SignExtend<BIT> {
+    const SHIFT: u32 = {
+        assert!(
+            BIT < 64,
+            "the sign bit must be within the 64 bit pointer value"
+        );
+        63 - BIT
+    };
+}
+
+impl<const BIT: u32> PointerPolicy for SignExtend<BIT> {
+    const ADDRESS_MASK: u64 = u64::MAX >> Self::SHIFT;
+
+    fn canonicalize(raw: u64) -> u64 {
+        (((raw << Self::SHIFT) as i64) >> Self::SHIFT) as u64
+    }
+}
+
+/// Canonical x86-64 addresses with 4-level paging (48 bit virtual addresses).
+pub type CanonicalAddress48 = SignExtend<47>;
+
+/// AArch64 Top-Byte-Ignore, the top byte is considered as tag.
+pub type TopByteIgnore = SignExtend<55>;
+
+/// Only keep the bits set in `MASK`.
+#[derive(Debug, Clone, marker::Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MaskBits<const MASK: u64>;
+
+impl<const MASK: u64> PointerPolicy for MaskBits<MASK> {
+    const ADDRESS_MASK: u64 = MASK;
+}
+
+/// Strip the lowest `BITS` bits, commonly used to store flags in aligned pointers.
+#[derive(Debug, Clone, marker::Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StripLowBits<const BITS: u32>;
+
+impl<const BITS: u32> PointerPolicy for StripLowBits<BITS> {
+    const ADDRESS_MASK: u64 = {
+        assert!(
+            BITS < 64,
+            "at most 63 bits can be stripped from the pointer value"
+        );
+        !((1u64 << BITS) - 1)
+    };
+}
+
+/// Apply policy `A` followed by policy `B`.
+impl<A: PointerPolicy, B: PointerPolicy> PointerPolicy for (A, B) {
+    const ADDRESS_MASK: u64 = A::ADDRESS_MASK & B::ADDRESS_MASK;
+
+    fn canonicalize(raw: u64) -> u64 {
+        B::canonicalize(A::canonicalize(raw))
+    }
+}
+
+/// Pointer types which can be followed without any additional context.
+pub trait Pointer: CopyConstructable {
+    /// The type the pointer points to.
+    type Target: ?Sized;
+
+    /// The address the pointer points to.
+    fn target_address(&self) -> u64;
+}
+
+/// A 64 bit pointer to `T`.
+/// The raw pointer value is turned into the actual address according to the pointer policy `P`.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Ptr64<T: ?Sized> {
+pub struct Ptr64<T: ?Sized, P = RawPointer> {
     address: u64,
     _type: PhantomData<T>,
+    _policy: PhantomData<P>,
 }
 
-impl<T: ?Sized> Clone for Ptr64<T> {
+impl<T: ?Sized, P> Clone for Ptr64<T, P> {
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<T: ?Sized> marker::Copy for Ptr64<T> {}
+impl<T: ?Sized, P> marker::Copy for Ptr64<T, P> {}
+
+impl<T: ?Sized, P> CopyConstructable for Ptr64<T, P> {}
+unsafe impl<T: ?Sized, P> PaddingFree for Ptr64<T, P> {}
 
-impl<T: ?Sized> CopyConstructable for Ptr64<T> {}
+impl<T: ?Sized, P> Ptr64<T, P> {
+    pub const fn from_address(address: u64) -> Self {
+        Self {
+            address,
+            _type: PhantomData {},
+            _policy: PhantomData {},
+        }
+    }
 
-impl<T: ?Sized> Ptr64<T> {
+    /// The raw pointer value including all tag bits.
+    /// Use [`Ptr64::resolved_address`] to get the address according to the pointer policy.
     pub const fn address(&self) -> u64 {
         self.address
     }
 
+    /// Returns true if the raw pointer value is zero.
     pub const fn is_null(&self) -> bool {
         self.address == 0
     }
 
-    pub const fn cast<V>(&self) -> Ptr64<V> {
-        Ptr64::<V> {
+    pub const fn cast<V>(&self) -> Ptr64<V, P> {
+        Ptr64::<V, P> {
+            address: self.address,
+            _type: PhantomData {},
+            _policy: PhantomData {},
+        }
+    }
+
+    /// Change the pointer policy while keeping the raw pointer value.
+    pub const fn with_policy<Q: PointerPolicy>(&self) -> Ptr64<T, Q> {
+        Ptr64::<T, Q> {
             address: self.address,
             _type: PhantomData {},
+            _policy: PhantomData {},
         }
     }
 }
 
-impl<T: FromMemoryView> Ptr64<T> {
+impl<T: ?Sized, P: PointerPolicy> Ptr64<T, P> {
+    /// The canonical address the pointer points to.
+    pub fn resolved_address(&self) -> u64 {
+        P::canonicalize(self.address)
+    }
+
+    /// The tag bits of the raw pointer value (at their original position).
+    pub fn tag(&self) -> u64 {
+        self.address & !P::ADDRESS_MASK
+    }
+
+    /// Returns true if the canonical address is zero.
+    /// A pointer carrying only tag bits is considered to be null.
+    pub fn is_resolved_null(&self) -> bool {
+        self.resolved_address() == 0
+    }
+}
+
+impl<T: ?Sized, P: PointerPolicy> Pointer for Ptr64<T, P> {
+    type Target = T;
+
+    fn target_address(&self) -> u64 {
+        self.resolved_address()
+    }
+}
+
+impl<T: FromMemoryView, P: PointerPolicy> Ptr64<T, P> {
     /// Create a copy of the value the pointer points to
     #[must_use = "copied result must be used"]
     pub fn read_value<M: MemoryView>(
         &self,
         memory: &M,
     ) -> Result<Option<T>, MemoryDecodeError<M::AccessError, T::DecodeError>> {
-        (self.address > 0)
-            .then(|| T::read_object(memory, self.address))
+        (!self.is_resolved_null())
+            .then(|| T::read_object(memory, self.resolved_address()))
             .transpose()
     }
 }
 
-impl<T: Viewable> Ptr64<T> {
+impl<T: Viewable, P: PointerPolicy> Ptr64<T, P> {
     #[must_use]
     pub fn reference_value<M: MemoryView>(&self, memory: M) -> Option<Reference<T, M>> {
-        (self.address > 0).then(|| Reference::new(memory, self.address))
+        (!self.is_resolved_null()).then(|| Reference::new(memory, self.resolved_address()))
     }
 }
 
-impl<T: ViewableSized> Ptr64<T> {
+impl<T: ViewableSized, P: PointerPolicy> Ptr64<T, P> {
     /// Create a copy of the value the pointer points to
     #[must_use = "copied result must be used"]
     pub fn copy_value<M: MemoryView>(&self, memory: &M) -> Result<Option<Copy<T>>, M::AccessError> {
-        (self.address > 0)
-            .then(|| Copy::<T>::read_from_memory(memory, self.address))
+        (!self.is_resolved_null())
+            .then(|| Copy::<T>::read_from_memory(memory, self.resolved_address()))
             .transpose()
     }
 }
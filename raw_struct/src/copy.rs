@@ -13,10 +13,17 @@ use core::{
 };
 
 use crate::{
-    memory::FromMemoryView,
+    memory::{
+        self,
+        FromMemoryView,
+    },
     MemoryView,
     OutOfBoundsViolation,
+    PaddingFree,
     Reference,
+    TypedViewableField,
+    ViewableExtends,
+    ViewableField,
     ViewableSized,
 };
 
@@ -60,6 +67,47 @@ impl<V: ViewableSized> Copy<V> {
     ) -> Result<Self, M::AccessError> {
         Self::read_object(memory, offset).map_err(|err| err.into_access_error())
     }
+
+    /// Set the value of a field.
+    /// Fails if the field exceeds the object.
+    pub fn set_field<C, R: PaddingFree>(
+        &mut self,
+        field: &TypedViewableField<C, R>,
+        value: R,
+    ) -> Result<(), OutOfBoundsViolation>
+    where
+        V: ViewableExtends<C>,
+    {
+        let value = memory::value_bytes(slice::from_ref(&value));
+        let memory_size = mem::size_of::<V::Memory>();
+
+        let field_range = usize::try_from(field.offset())
+            .ok()
+            .and_then(|offset| Some(offset..offset.checked_add(value.len())?))
+            .filter(|range| range.end <= memory_size);
+        let Some(field_range) = field_range else {
+            return Err(OutOfBoundsViolation {
+                access_offset: field.offset() as usize,
+                access_len: value.len(),
+                src_len: memory_size,
+            });
+        };
+
+        let memory = &mut self.inner.memory_mut().0;
+        let memory = unsafe { slice::from_raw_parts_mut(memory as *mut _ as *mut u8, memory_size) };
+        memory[field_range].copy_from_slice(value);
+        Ok(())
+    }
+
+    /// The raw binary contents of the object.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn to_bytes(&self) -> alloc::vec::Vec<u8> {
+        let mut buffer = alloc::vec![0u8; V::memory_size()];
+        self.memory()
+            .read_memory(0x00, &mut buffer)
+            .expect("copy to contain the whole object");
+        buffer
+    }
 }
 
 impl<V: ViewableSized> Deref for Copy<V> {
@@ -85,4 +133,8 @@ impl<V: ViewableSized> FromMemoryView for Copy<V> {
     ) -> Result<Self, crate::MemoryDecodeError<M::AccessError, Self::DecodeError>> {
         Ok(Self::new(V::Memory::read_object(view, offset)?))
     }
+
+    fn object_size() -> Option<usize> {
+        Some(V::memory_size())
+    }
 }
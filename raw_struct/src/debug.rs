@@ -0,0 +1,376 @@
+use core::fmt::{
+    self,
+    Debug,
+    DebugList,
+    DebugStruct,
+};
+
+use crate::{
+    ArrayValue,
+    Copy,
+    Describable,
+    FieldVisitor,
+    MemoryView,
+    MemoryViewDereferenceable,
+    PointerValue,
+    Reference,
+    StructValue,
+    Viewable,
+    ViewableField,
+    ViewableSized,
+};
+
+pub(crate) type DereferenceFn<M> = fn(&M, u64) -> Result<u64, <M as MemoryView>::AccessError>;
+
+/// Formats all fields of an object with their offsets.
+///
+/// Pointers are printed in hex and read errors are shown inline.
+/// By default pointers are not followed, see [`DebugFields::follow_pointers`].
+/// ```rust
+/// # use raw_struct::{raw_struct, Reference};
+/// #[raw_struct(size = 0x08)]
+/// struct MyStruct {
+///     #[field(offset = 0x00)]
+///     pub field_a: u32,
+///
+///     #[field(offset = 0x04)]
+///     pub field_b: u32,
+/// }
+///
+/// let memory = [0x01u8, 0x00, 0x00, 0x00];
+/// let object = Reference::<MyStruct, _>::new(memory.as_slice(), 0x00);
+/// assert_eq!(
+///     format!("{:?}", object),
+///     "MyStruct @ 0x0 { field_a: [0x00] 1, field_b: [0x04] <error: OutOfBoundsViolation { access_offset: 4, access_len: 4, src_len: 4 }> }"
+/// );
+/// ```
+pub struct DebugFields<'a, M: MemoryView> {
+    value: StructValue<'a, M>,
+    depth: usize,
+    dereference: Option<DereferenceFn<M>>,
+}
+
+impl<'a, M: MemoryView> DebugFields<'a, M> {
+    pub fn new(value: StructValue<'a, M>) -> Self {
+        Self {
+            value,
+            depth: 0,
+            dereference: None,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M: MemoryView> DebugFields<'_, M>
+where
+    M::AccessError: Debug,
+{
+    /// Invoke `callback` for every field with the formatted field value.
+    pub(crate) fn for_each_field(&self, callback: FieldCallback<'_>) {
+        let mut printer = FieldPrinter {
+            options: self,
+            builder: Builder::Callback(callback),
+        };
+        self.value.visit_fields(&mut printer);
+    }
+}
+
+impl<M: MemoryViewDereferenceable> DebugFields<'_, M> {
+    /// Follow pointers to structs and print the pointed to object up to `depth` levels deep.
+    pub fn follow_pointers(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self.dereference = Some(M::dereference);
+        self
+    }
+}
+
+impl<M: MemoryView> Debug for DebugFields<'_, M>
+where
+    M::AccessError: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} @ 0x{:X}",
+            self.value.type_info().name,
+            self.value.address()
+        )?;
+
+        let mut printer = FieldPrinter {
+            options: self,
+            builder: Builder::Struct(f.debug_struct("")),
+        };
+        self.value.visit_fields(&mut printer);
+        printer.builder.finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+type FieldCallback<'a> = &'a mut dyn FnMut(&dyn ViewableField, &dyn Debug);
+
+enum Builder<'a, 'b: 'a> {
+    Struct(DebugStruct<'a, 'b>),
+    List(DebugList<'a, 'b>),
+    #[cfg(feature = "alloc")]
+    Callback(FieldCallback<'a>),
+}
+
+impl Builder<'_, '_> {
+    fn finish(&mut self) -> fmt::Result {
+        match self {
+            Self::Struct(builder) => builder.finish(),
+            Self::List(builder) => builder.finish(),
+            #[cfg(feature = "alloc")]
+            Self::Callback(_) => Ok(()),
+        }
+    }
+}
+
+struct FieldPrinter<'o, 'a, 'f, 'b, M: MemoryView> {
+    options: &'o DebugFields<'a, M>,
+    builder: Builder<'f, 'b>,
+}
+
+impl<M: MemoryView> FieldPrinter<'_, '_, '_, '_, M>
+where
+    M::AccessError: Debug,
+{
+    fn push(&mut self, field: &dyn ViewableField, value: &dyn Debug) {
+        match &mut self.builder {
+            Builder::Struct(builder) => {
+                builder.field(
+                    field.name(),
+                    &WithOffset {
+                        offset: field.offset(),
+                        value,
+                    },
+                );
+            }
+            Builder::List(builder) => {
+                builder.entry(value);
+            }
+            #[cfg(feature = "alloc")]
+            Builder::Callback(callback) => callback(field, value),
+        }
+    }
+
+    fn push_result<T: Debug>(
+        &mut self,
+        field: &dyn ViewableField,
+        value: Result<T, M::AccessError>,
+    ) {
+        match value {
+            Ok(value) => self.push(field, &value),
+            Err(error) => self.push(field, &ReadError(&error)),
+        }
+    }
+}
+
+impl<'a, M: MemoryView> FieldVisitor<M> for FieldPrinter<'_, 'a, '_, '_, M>
+where
+    M::AccessError: Debug,
+{
+    fn visit_u8(&mut self, field: &dyn ViewableField, value: Result<u8, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_i8(&mut self, field: &dyn ViewableField, value: Result<i8, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_u16(&mut self, field: &dyn ViewableField, value: Result<u16, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_i16(&mut self, field: &dyn ViewableField, value: Result<i16, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_u32(&mut self, field: &dyn ViewableField, value: Result<u32, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_i32(&mut self, field: &dyn ViewableField, value: Result<i32, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_u64(&mut self, field: &dyn ViewableField, value: Result<u64, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_i64(&mut self, field: &dyn ViewableField, value: Result<i64, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_f32(&mut self, field: &dyn ViewableField, value: Result<f32, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_f64(&mut self, field: &dyn ViewableField, value: Result<f64, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_bool(&mut self, field: &dyn ViewableField, value: Result<bool, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_ptr(
+        &mut self,
+        field: &dyn ViewableField,
+        value: Result<PointerValue<'_, M>, M::AccessError>,
+    ) {
+        let value = match value {
+            Ok(value) => value,
+            Err(error) => return self.push(field, &ReadError(&error)),
+        };
+
+        let options = self.options;
+        self.push(
+            field,
+            &DebugFn(|f: &mut fmt::Formatter<'_>| {
+                if value.is_null() {
+                    return f.write_str("null");
+                }
+
+                let dereference = options
+                    .dereference
+                    .filter(|_| options.depth > 0 && value.pointee().fields().is_some());
+
+                let Some(dereference) = dereference else {
+                    return write!(f, "0x{:X}", value.address());
+                };
+
+                f.write_str("-> ")?;
+                match dereference(value.memory(), value.address()) {
+                    Ok(address) => StructValue::new(value.memory(), address, value.pointee())
+                        .debug_with(options, options.depth - 1)
+                        .fmt(f),
+                    Err(error) => ReadError(&error).fmt(f),
+                }
+            }),
+        );
+    }
+
+    fn visit_struct(&mut self, field: &dyn ViewableField, value: StructValue<'_, M>) {
+        let options = self.options;
+        self.push(
+            field,
+            &DebugFn(|f: &mut fmt::Formatter<'_>| value.debug_with(options, options.depth).fmt(f)),
+        );
+    }
+
+    fn visit_array(&mut self, field: &dyn ViewableField, value: ArrayValue<'_, M>) {
+        let options = self.options;
+        self.push(
+            field,
+            &DebugFn(|f: &mut fmt::Formatter<'_>| {
+                let Some(len) = value.len() else {
+                    return f.write_str("[..]");
+                };
+
+                let mut printer = FieldPrinter {
+                    options,
+                    builder: Builder::List(f.debug_list()),
+                };
+                for index in 0..len {
+                    value.visit_element(index, &mut printer);
+                }
+                printer.builder.finish()
+            }),
+        );
+    }
+
+    fn visit_opaque(&mut self, field: &dyn ViewableField) {
+        self.push(
+            field,
+            &DebugFn(|f: &mut fmt::Formatter<'_>| write!(f, "<{}>", field.type_info().name)),
+        );
+    }
+}
+
+impl<'a, M: MemoryView> StructValue<'a, M> {
+    fn debug_with(&self, options: &DebugFields<'_, M>, depth: usize) -> DebugFields<'a, M> {
+        DebugFields {
+            value: StructValue::new(self.memory(), self.address(), self.type_info()),
+            depth,
+            dereference: options.dereference,
+        }
+    }
+}
+
+struct WithOffset<'a> {
+    offset: u64,
+    value: &'a dyn Debug,
+}
+
+impl Debug for WithOffset<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[0x{:02X}] ", self.offset)?;
+        self.value.fmt(f)
+    }
+}
+
+struct DebugFn<F>(F);
+
+impl<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result> Debug for DebugFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+struct ReadError<'a, E>(&'a E);
+
+impl<E: Debug> Debug for ReadError<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<error: {:?}>", self.0)
+    }
+}
+
+impl<T: Viewable + Describable, M: MemoryView> Reference<T, M> {
+    /// Format all fields of the referenced object.
+    #[cfg_attr(feature = "alloc", doc = "```rust")]
+    #[cfg_attr(not(feature = "alloc"), doc = "```ignore")]
+    /// # use raw_struct::{raw_struct, builtins::Ptr64, views::MemoryImageBuilder, Reference};
+    /// #[raw_struct(size = 0x10)]
+    /// struct Node {
+    ///     #[field(offset = 0x00)]
+    ///     pub value: u32,
+    ///
+    ///     #[field(offset = 0x08)]
+    ///     pub next: Ptr64<Node>,
+    /// }
+    ///
+    /// let mut builder = MemoryImageBuilder::with_base_address(0x1000);
+    /// let node = builder.allocate::<Node>().unwrap();
+    /// builder.set_pointer(&node, Node::next, &node);
+    /// let memory = builder.build();
+    ///
+    /// let object = Reference::<Node, _>::new(&memory, node.address());
+    /// assert_eq!(
+    ///     format!("{:?}", object.debug_fields().follow_pointers(1)),
+    ///     "Node @ 0x1000 { value: [0x00] 0, next: [0x08] -> Node @ 0x1000 { value: [0x00] 0, next: [0x08] 0x1000 } }"
+    /// );
+    /// ```
+    pub fn debug_fields(&self) -> DebugFields<'_, M> {
+        DebugFields::new(StructValue::new(
+            self.memory(),
+            self.memory_address(),
+            T::type_info(),
+        ))
+    }
+}
+
+impl<T: Viewable + Describable, M: MemoryView> Debug for Reference<T, M>
+where
+    M::AccessError: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.debug_fields().fmt(f)
+    }
+}
+
+impl<T: ViewableSized + Describable> Debug for Copy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.debug_fields().fmt(f)
+    }
+}
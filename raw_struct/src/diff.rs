@@ -0,0 +1,258 @@
+use alloc::{
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::{
+    hex_dump::{
+        field_entries,
+        FieldEntry,
+    },
+    Copy,
+    Describable,
+    MemoryView,
+    Reference,
+    StructValue,
+    TypeInfo,
+    ViewableSized,
+};
+
+/// A declared field whose value changed.
+///
+/// Contains the raw bytes of the field. Fields of unknown size have no bytes,
+/// their decoded values are only available through the [`Display`](fmt::Display) of [`StructDiff`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FieldChange {
+    pub name: &'static str,
+    pub offset: u64,
+    pub old: Vec<u8>,
+    pub new: Vec<u8>,
+}
+
+/// A range of bytes not covered by any declared field which changed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ByteChange {
+    pub offset: u64,
+    pub old: Vec<u8>,
+    pub new: Vec<u8>,
+}
+
+impl ByteChange {
+    pub fn len(&self) -> usize {
+        self.old.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.old.is_empty()
+    }
+}
+
+/// Differences between two instances of the same struct.
+///
+/// Fields are compared by their underlying bytes, therefore nested structs and arrays
+/// are reported as a whole. Bytes not covered by any declared field are reported
+/// as contiguous [`ByteChange`] ranges.
+/// ```rust
+/// # use raw_struct::{raw_struct, Copy};
+/// #[raw_struct(size = 0x08)]
+/// struct MyStruct {
+///     #[field(offset = 0x00)]
+///     pub field_a: u32,
+/// }
+///
+/// let old = Copy::<MyStruct>::new([0x01, 0x00, 0x00, 0x00, 0xAA, 0xBB, 0xCC, 0xDD]);
+/// let new = Copy::<MyStruct>::new([0x02, 0x00, 0x00, 0x00, 0xAA, 0x00, 0x00, 0xDD]);
+///
+/// let diff = old.diff(&new);
+/// assert_eq!(diff.fields().len(), 1);
+/// assert_eq!(
+///     diff.to_string(),
+///     "field_a: 1 -> 2\n+0x0005..0x0007: BB CC -> 00 00\n"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct StructDiff {
+    type_info: TypeInfo,
+    old: Vec<u8>,
+    new: Vec<u8>,
+
+    fields: Vec<FieldChange>,
+    bytes: Vec<ByteChange>,
+}
+
+impl StructDiff {
+    /// Compare the raw bytes of two instances of `T`.
+    pub fn compare<T: ViewableSized + Describable>(old: &[u8], new: &[u8]) -> Self {
+        let old_entries = field_entries(&StructValue::new(&old, 0x00, T::type_info()));
+        let new_entries = field_entries(&StructValue::new(&new, 0x00, T::type_info()));
+
+        let len = old.len().min(new.len());
+        let mut covered = vec![false; len];
+        let mut fields = Vec::new();
+
+        for (old_entry, new_entry) in old_entries.into_iter().zip(new_entries) {
+            let start = old_entry.offset.min(len);
+            let end = old_entry.offset.saturating_add(old_entry.size).min(len);
+            covered[start..end]
+                .iter_mut()
+                .for_each(|value| *value = true);
+
+            let changed = if old_entry.size > 0 {
+                old[start..end] != new[start..end]
+            } else {
+                /* the size is unknown, compare by the formatted value instead */
+                old_entry.value != new_entry.value
+            };
+
+            if changed {
+                fields.push(FieldChange {
+                    name: old_entry.name,
+                    offset: old_entry.offset as u64,
+                    old: old[start..end].to_vec(),
+                    new: new[start..end].to_vec(),
+                });
+            }
+        }
+
+        let mut bytes: Vec<ByteChange> = Vec::new();
+        for offset in 0..len {
+            if covered[offset] || old[offset] == new[offset] {
+                continue;
+            }
+
+            match bytes.last_mut() {
+                Some(change) if change.offset as usize + change.len() == offset => {
+                    change.old.push(old[offset]);
+                    change.new.push(new[offset]);
+                }
+                _ => bytes.push(ByteChange {
+                    offset: offset as u64,
+                    old: vec![old[offset]],
+                    new: vec![new[offset]],
+                }),
+            }
+        }
+
+        Self {
+            type_info: T::type_info(),
+            old: old.to_vec(),
+            new: new.to_vec(),
+
+            fields,
+            bytes,
+        }
+    }
+
+    /// Changed declared fields ordered by their offset.
+    pub fn fields(&self) -> &[FieldChange] {
+        &self.fields
+    }
+
+    /// Changed bytes not covered by any declared field.
+    pub fn bytes(&self) -> &[ByteChange] {
+        &self.bytes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.bytes.is_empty()
+    }
+}
+
+impl PartialEq for StructDiff {
+    fn eq(&self, other: &Self) -> bool {
+        self.fields == other.fields && self.bytes == other.bytes
+    }
+}
+
+impl Eq for StructDiff {}
+
+impl fmt::Debug for StructDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StructDiff")
+            .field("type", &self.type_info.name)
+            .field("fields", &self.fields)
+            .field("bytes", &self.bytes)
+            .finish()
+    }
+}
+
+impl fmt::Display for StructDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.fields.is_empty() {
+            let old_entries = field_entries(&StructValue::new(
+                &self.old.as_slice(),
+                0x00,
+                self.type_info,
+            ));
+            let new_entries = field_entries(&StructValue::new(
+                &self.new.as_slice(),
+                0x00,
+                self.type_info,
+            ));
+
+            for change in &self.fields {
+                writeln!(
+                    f,
+                    "{}: {} -> {}",
+                    change.name,
+                    entry_value(&old_entries, change),
+                    entry_value(&new_entries, change)
+                )?;
+            }
+        }
+
+        for change in &self.bytes {
+            write!(
+                f,
+                "+0x{:04X}..0x{:04X}: ",
+                change.offset,
+                change.offset as usize + change.len()
+            )?;
+            write_hex(f, &change.old)?;
+            f.write_str(" -> ")?;
+            write_hex(f, &change.new)?;
+            f.write_str("\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The formatted value of the field described by `change`.
+fn entry_value<'a>(entries: &'a [FieldEntry], change: &FieldChange) -> &'a str {
+    entries
+        .iter()
+        .find(|entry| entry.name == change.name && entry.offset as u64 == change.offset)
+        .map_or("", |entry| entry.value.as_str())
+}
+
+fn write_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    for (index, byte) in bytes.iter().enumerate() {
+        if index > 0 {
+            f.write_str(" ")?;
+        }
+        write!(f, "{:02X}", byte)?;
+    }
+
+    Ok(())
+}
+
+impl<T: ViewableSized + Describable> Copy<T> {
+    /// Compare this copy against a newer copy of the same object.
+    /// See [`StructDiff`] for more details.
+    pub fn diff(&self, new: &Self) -> StructDiff {
+        StructDiff::compare::<T>(&self.to_bytes(), &new.to_bytes())
+    }
+}
+
+impl<T: ViewableSized + Describable, M: MemoryView> Reference<T, M> {
+    /// Read both objects and compare them.
+    /// See [`StructDiff`] for more details.
+    pub fn diff<N: MemoryView<AccessError = M::AccessError>>(
+        &self,
+        new: &Reference<T, N>,
+    ) -> Result<StructDiff, M::AccessError> {
+        Ok(self.create_copy()?.diff(&new.create_copy()?))
+    }
+}
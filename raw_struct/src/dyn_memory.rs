@@ -0,0 +1,154 @@
+use alloc::boxed::Box;
+#[cfg(not(feature = "std"))]
+use core::error::Error;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{
+    MemoryView,
+    MemoryViewDereferenceable,
+};
+
+type ErrorObject = dyn Error + Send + Sync + 'static;
+
+/// Type erased access error of a [`DynMemoryView`].
+pub struct DynAccessError(Box<ErrorObject>);
+
+impl DynAccessError {
+    pub fn new<E: Into<Box<ErrorObject>>>(error: E) -> Self {
+        Self(error.into())
+    }
+
+    pub fn inner(&self) -> &ErrorObject {
+        &*self.0
+    }
+
+    pub fn into_inner(self) -> Box<ErrorObject> {
+        self.0
+    }
+
+    /// Returns the original access error if it is of type `E`.
+    pub fn downcast_ref<E: Error + 'static>(&self) -> Option<&E> {
+        self.0.downcast_ref::<E>()
+    }
+}
+
+impl fmt::Debug for DynAccessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for DynAccessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Error for DynAccessError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+/// Object safe variant of [`MemoryView`] with a type erased access error.
+///
+/// Implemented for every [`MemoryView`] which access error implements `Error + Send + Sync`.
+/// `dyn DynMemoryView` itself implements [`MemoryView`] and therefore can be used like any other memory view:
+/// ```rust
+/// # use raw_struct::{DynMemoryView, MemoryView};
+/// let memory = [0x11u8; 0x10];
+/// let memory: Box<dyn DynMemoryView> = Box::new(memory.as_slice());
+///
+/// let mut buffer = [0u8; 0x04];
+/// memory.read_memory(0x00, &mut buffer).unwrap();
+/// assert!(memory.read_memory(0x0E, &mut buffer).is_err());
+/// ```
+pub trait DynMemoryView {
+    fn read_memory_dyn(&self, offset: u64, buffer: &mut [u8]) -> Result<(), DynAccessError>;
+
+    fn read_memory_batch_dyn(
+        &self,
+        requests: &mut [(u64, &mut [u8])],
+    ) -> Result<(), DynAccessError>;
+}
+
+impl<M: MemoryView> DynMemoryView for M
+where
+    M::AccessError: Error + Send + Sync + 'static,
+{
+    fn read_memory_dyn(&self, offset: u64, buffer: &mut [u8]) -> Result<(), DynAccessError> {
+        self.read_memory(offset, buffer)
+            .map_err(DynAccessError::new)
+    }
+
+    fn read_memory_batch_dyn(
+        &self,
+        requests: &mut [(u64, &mut [u8])],
+    ) -> Result<(), DynAccessError> {
+        self.read_memory_batch(requests)
+            .map_err(DynAccessError::new)
+    }
+}
+
+/// Object safe variant of [`MemoryViewDereferenceable`] with a type erased access error.
+pub trait DynMemoryViewDereferenceable: DynMemoryView {
+    fn dereference_dyn(&self, address: u64) -> Result<u64, DynAccessError>;
+}
+
+impl<M: MemoryViewDereferenceable> DynMemoryViewDereferenceable for M
+where
+    M::AccessError: Error + Send + Sync + 'static,
+{
+    fn dereference_dyn(&self, address: u64) -> Result<u64, DynAccessError> {
+        self.dereference(address).map_err(DynAccessError::new)
+    }
+}
+
+macro_rules! impl_dyn_memory_view {
+    ($($target:ty),*) => {
+        $(
+            impl MemoryView for $target {
+                type AccessError = DynAccessError;
+
+                fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+                    self.read_memory_dyn(offset, buffer)
+                }
+
+                fn read_memory_batch(
+                    &self,
+                    requests: &mut [(u64, &mut [u8])],
+                ) -> Result<(), Self::AccessError> {
+                    self.read_memory_batch_dyn(requests)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_dyn_memory_view_dereferenceable {
+    ($($target:ty),*) => {
+        impl_dyn_memory_view!($($target),*);
+
+        $(
+            impl MemoryViewDereferenceable for $target {
+                fn dereference(&self, address: u64) -> Result<u64, Self::AccessError> {
+                    self.dereference_dyn(address)
+                }
+            }
+        )*
+    };
+}
+
+impl_dyn_memory_view!(
+    dyn DynMemoryView + '_,
+    dyn DynMemoryView + Send + '_,
+    dyn DynMemoryView + Send + Sync + '_
+);
+
+impl_dyn_memory_view_dereferenceable!(
+    dyn DynMemoryViewDereferenceable + '_,
+    dyn DynMemoryViewDereferenceable + Send + '_,
+    dyn DynMemoryViewDereferenceable + Send + Sync + '_
+);
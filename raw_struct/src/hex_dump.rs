@@ -0,0 +1,363 @@
+use alloc::{
+    format,
+    string::String,
+    vec,
+    vec::Vec,
+};
+use core::{
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+    mem,
+};
+
+use crate::{
+    builtins::chain::ChainPointer,
+    DebugFields,
+    Describable,
+    MemoryView,
+    Reference,
+    StructValue,
+    ViewableField,
+    ViewableSized,
+};
+
+const BYTES_PER_LINE: usize = 16;
+
+/// Unreadable objects are read in chunks of this size to find the readable bytes.
+const READ_CHUNK_SIZE: u64 = 0x1000;
+
+const COLOR_GAP: &str = "\x1b[33m";
+const COLOR_WARNING: &str = "\x1b[31m";
+const COLOR_RESET: &str = "\x1b[0m";
+
+/// Hex dump of an object annotated with the fields of its layout.
+///
+/// Every field is printed with its offset, raw bytes and decoded value.
+/// Bytes not covered by any field are marked with `??` and fields overlapping
+/// previous fields or exceeding the object size are marked with `!!`.
+/// Only the bytes within the object size are dumped.
+/// Use [`HexDump::colored`] to highlight these rows with ANSI colors.
+/// ```rust
+/// # use raw_struct::{raw_struct, Reference};
+/// #[raw_struct(size = 0x0C)]
+/// struct MyStruct {
+///     #[field(offset = 0x00)]
+///     pub field_a: u32,
+///
+///     #[field(offset = 0x08)]
+///     pub field_b: u16,
+/// }
+///
+/// let memory = [0x01u8, 0x00, 0x00, 0x00, 0xAA, 0xBB, 0xCC, 0xDD, 0x02, 0x00, 0xEE, 0xFF];
+/// let object = Reference::<MyStruct, _>::new(memory.as_slice(), 0x00);
+/// assert_eq!(
+///     object.hex_dump().to_string(),
+///     concat!(
+///         "MyStruct @ 0x0 (0xC bytes)\n",
+///         "   +0x0000  01 00 00 00                                      field_a = 1\n",
+///         "?? +0x0004  AA BB CC DD                                      <unknown>\n",
+///         "   +0x0008  02 00                                            field_b = 2\n",
+///         "?? +0x000A  EE FF                                            <unknown>\n",
+///     )
+/// );
+/// ```
+pub struct HexDump<'a, M: MemoryView> {
+    value: StructValue<'a, M>,
+    size: usize,
+    colored: bool,
+}
+
+impl<'a, M: MemoryView> HexDump<'a, M> {
+    pub fn new(value: StructValue<'a, M>, size: usize) -> Self {
+        Self {
+            value,
+            size,
+            colored: false,
+        }
+    }
+
+    /// Highlight unknown bytes and invalid fields with ANSI colors.
+    pub fn colored(mut self) -> Self {
+        self.colored = true;
+        self
+    }
+}
+
+/// A field of an object with its formatted value.
+pub(crate) struct FieldEntry {
+    pub name: &'static str,
+    pub offset: usize,
+
+    /// Size of the field in bytes, zero if unknown.
+    pub size: usize,
+    pub value: String,
+}
+
+/// Collect all fields of the struct sorted by their offset.
+pub(crate) fn field_entries<M: MemoryView>(value: &StructValue<'_, M>) -> Vec<FieldEntry>
+where
+    M::AccessError: Debug,
+{
+    let mut entries = Vec::new();
+    DebugFields::new(StructValue::new(
+        value.memory(),
+        value.address(),
+        value.type_info(),
+    ))
+    .for_each_field(&mut |field: &dyn ViewableField, value: &dyn Debug| {
+        let size = if field.chain().is_empty() {
+            field.type_info().size.unwrap_or(0)
+        } else {
+            /* the chain starts with a pointer within the object */
+            mem::size_of::<ChainPointer>()
+        };
+
+        entries.push(FieldEntry {
+            name: field.name(),
+            offset: field.offset() as usize,
+            size,
+            value: format!("{:?}", value),
+        });
+    });
+
+    entries.sort_by_key(|entry| (entry.offset, usize::MAX - entry.size));
+    entries
+}
+
+enum RowKind {
+    Field,
+    Gap,
+    Warning(&'static str),
+}
+
+impl<M: MemoryView> HexDump<'_, M> {
+    fn read_bytes(&self, len: usize) -> Vec<Option<u8>> {
+        let memory = self.value.memory();
+        let address = self.value.address();
+
+        let mut bytes = vec![None; len];
+        if read_range(memory, address, &mut bytes) {
+            return bytes;
+        }
+
+        /* read chunk by chunk to find the readable bytes */
+        let mut offset = 0;
+        while offset < len {
+            let Some(chunk_address) = address.checked_add(offset as u64) else {
+                break;
+            };
+
+            let chunk_len = (READ_CHUNK_SIZE - chunk_address % READ_CHUNK_SIZE) as usize;
+            let chunk_end = len.min(offset + chunk_len);
+            read_range_bisect(memory, chunk_address, &mut bytes[offset..chunk_end]);
+            offset = chunk_end;
+        }
+
+        bytes
+    }
+}
+
+/// Read all bytes of the range at once.
+fn read_range<M: MemoryView>(memory: &M, address: u64, bytes: &mut [Option<u8>]) -> bool {
+    if address.checked_add(bytes.len() as u64).is_none() {
+        return false;
+    }
+
+    let mut buffer = vec![0u8; bytes.len()];
+    if memory.read_memory(address, &mut buffer).is_err() {
+        return false;
+    }
+
+    for (byte, value) in bytes.iter_mut().zip(buffer) {
+        *byte = Some(value);
+    }
+    true
+}
+
+/// Read the readable bytes of a range which can not be read at once.
+/// The readable bytes are expected at the start and the end of the range.
+fn read_range_bisect<M: MemoryView>(memory: &M, address: u64, bytes: &mut [Option<u8>]) {
+    if read_range(memory, address, bytes) {
+        return;
+    }
+
+    /* bisect the longest readable prefix */
+    let (mut readable, mut unreadable) = (0, bytes.len());
+    while unreadable - readable > 1 {
+        let len = (readable + unreadable) / 2;
+        if read_range(memory, address, &mut bytes[..len]) {
+            readable = len;
+        } else {
+            unreadable = len;
+        }
+    }
+
+    /* bisect the longest readable suffix excluding the first unreadable byte */
+    let prefix = readable;
+    let (mut readable, mut unreadable) = (0, bytes.len() - prefix);
+    while unreadable - readable > 1 {
+        let len = (readable + unreadable) / 2;
+        let start = bytes.len() - len;
+        if read_range(memory, address + start as u64, &mut bytes[start..]) {
+            readable = len;
+        } else {
+            unreadable = len;
+        }
+    }
+}
+
+impl<M: MemoryView> Display for HexDump<'_, M>
+where
+    M::AccessError: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries = field_entries(&self.value);
+        let bytes = self.read_bytes(self.size);
+
+        writeln!(
+            f,
+            "{} @ 0x{:X} (0x{:X} bytes)",
+            self.value.type_info().name,
+            self.value.address(),
+            self.size
+        )?;
+
+        let mut cursor = 0;
+        for entry in &entries {
+            let gap_end = entry.offset.min(self.size);
+            if gap_end > cursor {
+                write_row(
+                    f,
+                    &bytes,
+                    cursor,
+                    gap_end,
+                    RowKind::Gap,
+                    "<unknown>",
+                    self.colored,
+                )?;
+            }
+
+            let end = entry
+                .offset
+                .checked_add(entry.size)
+                .filter(|end| *end <= self.size);
+
+            let kind = if entry.offset < cursor {
+                RowKind::Warning("overlaps previous field")
+            } else if end.is_none() {
+                RowKind::Warning("exceeds object size")
+            } else {
+                RowKind::Field
+            };
+
+            /* only the bytes within the object are dumped */
+            let end = end.unwrap_or(self.size).max(entry.offset);
+
+            let annotation = format!("{} = {}", entry.name, entry.value);
+            write_row(
+                f,
+                &bytes,
+                entry.offset,
+                end,
+                kind,
+                &annotation,
+                self.colored,
+            )?;
+            cursor = cursor.max(end);
+        }
+
+        if cursor < self.size {
+            write_row(
+                f,
+                &bytes,
+                cursor,
+                self.size,
+                RowKind::Gap,
+                "<unknown>",
+                self.colored,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_row(
+    f: &mut fmt::Formatter<'_>,
+    bytes: &[Option<u8>],
+    start: usize,
+    end: usize,
+    kind: RowKind,
+    annotation: &str,
+    colored: bool,
+) -> fmt::Result {
+    let (marker, color, note) = match kind {
+        RowKind::Field => ("  ", None, None),
+        RowKind::Gap => ("??", Some(COLOR_GAP), None),
+        RowKind::Warning(note) => ("!!", Some(COLOR_WARNING), Some(note)),
+    };
+    let color = color.filter(|_| colored);
+
+    if let Some(color) = color {
+        f.write_str(color)?;
+    }
+
+    let mut line_start = start;
+    loop {
+        let line_end = end.min(line_start.saturating_add(BYTES_PER_LINE));
+        let hex = bytes
+            .get(line_start..line_end)
+            .unwrap_or_default()
+            .iter()
+            .map(|byte| match byte {
+                Some(byte) => format!("{:02X}", byte),
+                None => String::from("??"),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if line_start == start {
+            write!(
+                f,
+                "{} +0x{:04X}  {:<width$}  {}",
+                marker,
+                line_start,
+                hex,
+                annotation,
+                width = BYTES_PER_LINE * 3 - 1
+            )?;
+            if let Some(note) = note {
+                write!(f, " ({})", note)?;
+            }
+        } else {
+            write!(f, "{} +0x{:04X}  {}", marker, line_start, hex)?;
+        }
+
+        if line_end >= end {
+            break;
+        }
+
+        f.write_str("\n")?;
+        line_start = line_end;
+    }
+
+    if color.is_some() {
+        f.write_str(COLOR_RESET)?;
+    }
+
+    f.write_str("\n")
+}
+
+impl<T: ViewableSized + Describable, M: MemoryView> Reference<T, M> {
+    /// Hex dump of the referenced object annotated with its fields.
+    /// See [`HexDump`] for more details.
+    pub fn hex_dump(&self) -> HexDump<'_, M> {
+        HexDump::new(
+            StructValue::new(self.memory(), self.memory_address(), T::type_info()),
+            T::memory_size(),
+        )
+    }
+}
@@ -16,19 +16,92 @@ pub use memory::{
     FromMemoryView,
     MemoryView,
     MemoryViewDereferenceable,
+    PaddingFree,
+};
+
+#[cfg(feature = "alloc")]
+mod dyn_memory;
+#[cfg(feature = "alloc")]
+pub use dyn_memory::{
+    DynAccessError,
+    DynMemoryView,
+    DynMemoryViewDereferenceable,
+};
+
+mod async_memory;
+pub use async_memory::{
+    AsyncAdapter,
+    AsyncFromMemoryView,
+    AsyncMemoryView,
+    AsyncMemoryViewDereferenceable,
 };
 
 mod reference;
 pub use reference::Reference;
 
+mod batch;
+pub use batch::FieldBatch;
+
 mod copy;
 pub use copy::{
     Copy,
     CopyMemory,
 };
 
+mod type_info;
+pub use type_info::{
+    Describable,
+    PrimitiveType,
+    TypeInfo,
+    TypeKind,
+};
+#[doc(hidden)]
+pub use type_info::{
+    DescribeKnown,
+    DescribeOpaque,
+    DescribePointer,
+    TypeInfoProbe,
+};
+
+mod visitor;
+pub use visitor::{
+    ArrayValue,
+    FieldVisitor,
+    PointerValue,
+    StructValue,
+};
+
+mod debug;
+pub use debug::DebugFields;
+
+#[cfg(feature = "alloc")]
+mod hex_dump;
+#[cfg(feature = "alloc")]
+pub use hex_dump::HexDump;
+
+#[cfg(feature = "alloc")]
+mod diff;
+#[cfg(feature = "alloc")]
+pub use diff::{
+    ByteChange,
+    FieldChange,
+    StructDiff,
+};
+
+#[cfg(feature = "std")]
+mod watcher;
+#[cfg(feature = "std")]
+pub use watcher::{
+    ChangeEvent,
+    ChangeFilter,
+    Crossing,
+    FieldWatcher,
+    WatchError,
+};
+
 mod view;
 pub use view::{
+    ChainedViewableField,
     Viewable,
     ViewableExtends,
     TypedViewableField,
@@ -37,6 +110,16 @@ pub use view::{
 };
 
 pub mod builtins;
+pub mod views;
+
+#[cfg(feature = "alloc")]
+pub mod path;
+
+#[cfg(feature = "serde")]
+pub mod serde;
 
 // Re-exports
-pub use raw_struct_derive::raw_struct;
\ No newline at end of file
+pub use raw_struct_derive::{
+    raw_struct,
+    RawLayout,
+};
\ No newline at end of file
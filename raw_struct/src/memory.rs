@@ -10,6 +10,10 @@ use core::{
 
 use crate::{
     error::OutOfBoundsViolation,
+    views::{
+        RebasedMemory,
+        WindowMemory,
+    },
     MemoryDecodeError,
 };
 
@@ -17,14 +21,53 @@ pub trait MemoryView {
     type AccessError;
 
     fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError>;
+
+    /// Read multiple memory ranges at once.
+    /// Backends which support vectored reads (e.g. `process_vm_readv`) should
+    /// override this method to serve all requests with a single call.
+    ///
+    /// Requests are processed in order and the first error will be returned.
+    fn read_memory_batch(
+        &self,
+        requests: &mut [(u64, &mut [u8])],
+    ) -> Result<(), Self::AccessError> {
+        for (offset, buffer) in requests.iter_mut() {
+            self.read_memory(*offset, buffer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Restrict reads to the address range `base..base + len`.
+    fn window(self, base: u64, len: u64) -> WindowMemory<Self>
+    where
+        Self: Sized,
+    {
+        WindowMemory::new(self, base, len)
+    }
+
+    /// Translate every read address by `delta`.
+    fn rebase(self, delta: i64) -> RebasedMemory<Self>
+    where
+        Self: Sized,
+    {
+        RebasedMemory::new(self, delta)
+    }
 }
 
-impl<M: MemoryView> MemoryView for &M {
+impl<M: ?Sized + MemoryView> MemoryView for &M {
     type AccessError = M::AccessError;
 
     fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
         M::read_memory(self, offset, buffer)
     }
+
+    fn read_memory_batch(
+        &self,
+        requests: &mut [(u64, &mut [u8])],
+    ) -> Result<(), Self::AccessError> {
+        M::read_memory_batch(self, requests)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -34,6 +77,45 @@ impl<M: ?Sized + MemoryView> MemoryView for alloc::sync::Arc<M> {
     fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
         M::read_memory(self, offset, buffer)
     }
+
+    fn read_memory_batch(
+        &self,
+        requests: &mut [(u64, &mut [u8])],
+    ) -> Result<(), Self::AccessError> {
+        M::read_memory_batch(self, requests)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M: ?Sized + MemoryView> MemoryView for alloc::rc::Rc<M> {
+    type AccessError = M::AccessError;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        M::read_memory(self, offset, buffer)
+    }
+
+    fn read_memory_batch(
+        &self,
+        requests: &mut [(u64, &mut [u8])],
+    ) -> Result<(), Self::AccessError> {
+        M::read_memory_batch(self, requests)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M: ?Sized + MemoryView> MemoryView for alloc::boxed::Box<M> {
+    type AccessError = M::AccessError;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        M::read_memory(self, offset, buffer)
+    }
+
+    fn read_memory_batch(
+        &self,
+        requests: &mut [(u64, &mut [u8])],
+    ) -> Result<(), Self::AccessError> {
+        M::read_memory_batch(self, requests)
+    }
 }
 
 impl MemoryView for &[u8] {
@@ -59,16 +141,30 @@ pub trait MemoryViewDereferenceable: MemoryView {
     fn dereference(&self, address: u64) -> Result<u64, Self::AccessError>;
 }
 
-impl<M: MemoryViewDereferenceable> MemoryViewDereferenceable for &M {
+impl<M: ?Sized + MemoryViewDereferenceable> MemoryViewDereferenceable for &M {
     fn dereference(&self, address: u64) -> Result<u64, Self::AccessError> {
-        M::dereference(&self, address)
+        M::dereference(self, address)
     }
 }
 
 #[cfg(feature = "alloc")]
 impl<M: ?Sized + MemoryViewDereferenceable> MemoryViewDereferenceable for alloc::sync::Arc<M> {
     fn dereference(&self, address: u64) -> Result<u64, Self::AccessError> {
-        M::dereference(&self, address)
+        M::dereference(self, address)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M: ?Sized + MemoryViewDereferenceable> MemoryViewDereferenceable for alloc::rc::Rc<M> {
+    fn dereference(&self, address: u64) -> Result<u64, Self::AccessError> {
+        M::dereference(self, address)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M: ?Sized + MemoryViewDereferenceable> MemoryViewDereferenceable for alloc::boxed::Box<M> {
+    fn dereference(&self, address: u64) -> Result<u64, Self::AccessError> {
+        M::dereference(self, address)
     }
 }
 
@@ -84,6 +180,12 @@ pub trait FromMemoryView: Sized {
         offset: u64,
     ) -> Result<Self, MemoryDecodeError<M::AccessError, Self::DecodeError>>;
 
+    /// Number of bytes read by [`FromMemoryView::read_object`] if known in advance.
+    /// Objects of a known size can be read within batched reads.
+    fn object_size() -> Option<usize> {
+        None
+    }
+
     // fn read_boxed(view: &dyn MemoryView, offset: u64) -> Result<Box<Self>, Box<dyn error::ErrorType>>;
 }
 
@@ -114,25 +216,64 @@ impl<T: CopyConstructable> FromMemoryView for T {
 
         Ok(unsafe { result.assume_init() })
     }
+
+    fn object_size() -> Option<usize> {
+        Some(mem::size_of::<T>())
+    }
+}
+
+/// Marker trait for [`CopyConstructable`] types without any padding bytes.
+///
+/// Values of these types can be safely viewed as their raw binary representation.
+/// Tuples are not padding free in general and therefore do not implement this trait:
+/// ```compile_fail
+/// # use raw_struct::views::MemoryImageBuilder;
+/// let mut builder = MemoryImageBuilder::new();
+/// builder.allocate_array(&[(0x01u8, 0x02u64)]).unwrap();
+/// ```
+///
+/// # Safety
+/// Every byte of the type must be initialized for all valid values.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not known to be padding free",
+    label = "`{Self}` does not implement `PaddingFree`",
+    note = "only `CopyConstructable` types without padding bytes can be converted from and into raw bytes"
+)]
+pub unsafe trait PaddingFree: CopyConstructable {}
+
+/// The raw binary representation of `values`.
+pub(crate) fn value_bytes<T: PaddingFree>(values: &[T]) -> &[u8] {
+    unsafe { slice::from_raw_parts(values.as_ptr() as *const u8, mem::size_of_val(values)) }
 }
 
 impl<T1: CopyConstructable, T2: CopyConstructable> CopyConstructable for (T1, T2) {}
 impl<T: CopyConstructable, const N: usize> CopyConstructable for [T; N] {}
+unsafe impl<T: PaddingFree, const N: usize> PaddingFree for [T; N] {}
 
 impl CopyConstructable for u8 {}
+unsafe impl PaddingFree for u8 {}
 impl CopyConstructable for i8 {}
+unsafe impl PaddingFree for i8 {}
 
 impl CopyConstructable for u16 {}
+unsafe impl PaddingFree for u16 {}
 impl CopyConstructable for i16 {}
+unsafe impl PaddingFree for i16 {}
 
 impl CopyConstructable for u32 {}
+unsafe impl PaddingFree for u32 {}
 impl CopyConstructable for i32 {}
+unsafe impl PaddingFree for i32 {}
 
 impl CopyConstructable for u64 {}
+unsafe impl PaddingFree for u64 {}
 impl CopyConstructable for i64 {}
+unsafe impl PaddingFree for i64 {}
 
 impl CopyConstructable for f32 {}
+unsafe impl PaddingFree for f32 {}
 impl CopyConstructable for f64 {}
+unsafe impl PaddingFree for f64 {}
 
 impl FromMemoryView for bool {
     type DecodeError = Infallible;
@@ -144,6 +285,10 @@ impl FromMemoryView for bool {
         let value = u8::read_object(view, offset)?;
         Ok(value > 0)
     }
+
+    fn object_size() -> Option<usize> {
+        Some(1)
+    }
 }
 
 #[cfg(test)]
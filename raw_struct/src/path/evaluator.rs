@@ -0,0 +1,345 @@
+use alloc::{
+    collections::BTreeMap,
+    string::String,
+};
+use core::{
+    convert::Infallible,
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+};
+
+use super::{
+    LayoutRegistry,
+    LayoutType,
+    PathExpression,
+    PathParseError,
+    PathSegment,
+    PrimitiveType,
+    Value,
+};
+use crate::{
+    FromMemoryView,
+    MemoryViewDereferenceable,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PathError<A> {
+    Parse(PathParseError),
+
+    /// The expression root has not been registered.
+    UnknownRoot(String),
+
+    /// No layout with the given name has been registered.
+    UnknownLayout(String),
+
+    UnknownField {
+        layout: String,
+        field: String,
+    },
+
+    /// The size of the type is required to index it but unknown.
+    UnknownSize(LayoutType),
+
+    /// The segment can not be applied to the value.
+    /// E.g. a member access on a pointer or indexing a struct.
+    InvalidSegment {
+        segment: PathSegment,
+        value: Value,
+    },
+
+    IndexOutOfBounds {
+        index: usize,
+        len: usize,
+    },
+
+    /// The segment tried to dereference a null pointer.
+    NullPointer(PathSegment),
+
+    /// The address of a member or element located relative to `address` exceeds the address space.
+    AddressOverflow {
+        address: u64,
+    },
+
+    MemoryAccess(A),
+}
+
+impl<A: Display> fmt::Display for PathError<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(inner) => Display::fmt(inner, f),
+            Self::UnknownRoot(name) => write!(f, "unknown root {}", name),
+            Self::UnknownLayout(name) => write!(f, "unknown layout {}", name),
+            Self::UnknownField { layout, field } => {
+                write!(f, "layout {} has no field {}", layout, field)
+            }
+            Self::UnknownSize(value_type) => write!(f, "size of {} is unknown", value_type),
+            Self::InvalidSegment { segment, value } => {
+                write!(f, "{} can not be applied to {}", segment, value)
+            }
+            Self::IndexOutOfBounds { index, len } => {
+                write!(f, "index {} is out of bounds (len {})", index, len)
+            }
+            Self::NullPointer(segment) => write!(f, "{} dereferences a null pointer", segment),
+            Self::AddressOverflow { address } => write!(
+                f,
+                "value relative to 0x{:X} exceeds the address space",
+                address
+            ),
+            Self::MemoryAccess(inner) => Display::fmt(inner, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A: Display + Debug> std::error::Error for PathError<A> {}
+
+#[cfg(not(feature = "std"))]
+impl<A: Display + Debug> core::error::Error for PathError<A> {}
+
+/// Evaluate [`PathExpression`]s against the memory using the layouts of a [`LayoutRegistry`].
+/// ```rust
+/// # use raw_struct::path::{Layout, LayoutRegistry, LayoutType, PathEvaluator, PrimitiveType, Value};
+/// # use raw_struct::views::SparseMemory;
+/// let mut registry = LayoutRegistry::new();
+/// registry.register(
+///     Layout::new("Player")
+///         .with_field("health", 0x00, PrimitiveType::U32)
+///         .with_field("scores", 0x04, LayoutType::array(PrimitiveType::U16.into(), 2)),
+/// );
+///
+/// let mut memory = SparseMemory::new();
+/// memory.insert(0x1000, [0x64u8, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00])?;
+///
+/// let evaluator = PathEvaluator::new(&registry, &memory)
+///     .with_root("player", Value::structure("Player", 0x1000));
+///
+/// assert_eq!(evaluator.evaluate("player.health"), Ok(Value::U32(100)));
+/// assert_eq!(evaluator.evaluate("player.scores[1]"), Ok(Value::U16(2)));
+/// # Ok::<(), raw_struct::views::RegionOverlap>(())
+/// ```
+pub struct PathEvaluator<'a, M> {
+    registry: &'a LayoutRegistry,
+    memory: M,
+    roots: BTreeMap<String, Value>,
+}
+
+impl<'a, M: MemoryViewDereferenceable> PathEvaluator<'a, M> {
+    pub fn new(registry: &'a LayoutRegistry, memory: M) -> Self {
+        Self {
+            registry,
+            memory,
+            roots: Default::default(),
+        }
+    }
+
+    pub fn with_root(mut self, name: impl Into<String>, value: Value) -> Self {
+        self.set_root(name, value);
+        self
+    }
+
+    /// Register a named root value.
+    /// Returns the previous value of the root.
+    pub fn set_root(&mut self, name: impl Into<String>, value: Value) -> Option<Value> {
+        self.roots.insert(name.into(), value)
+    }
+
+    pub fn memory(&self) -> &M {
+        &self.memory
+    }
+
+    pub fn registry(&self) -> &'a LayoutRegistry {
+        self.registry
+    }
+
+    /// Parse and evaluate the expression.
+    pub fn evaluate(&self, expression: &str) -> Result<Value, PathError<M::AccessError>> {
+        let expression = PathExpression::parse(expression).map_err(PathError::Parse)?;
+        self.evaluate_expression(&expression)
+    }
+
+    pub fn evaluate_expression(
+        &self,
+        expression: &PathExpression,
+    ) -> Result<Value, PathError<M::AccessError>> {
+        let mut value = self
+            .roots
+            .get(expression.root())
+            .cloned()
+            .ok_or_else(|| PathError::UnknownRoot(expression.root().into()))?;
+
+        for segment in expression.segments() {
+            value = self.apply_segment(segment, value)?;
+        }
+
+        Ok(value)
+    }
+
+    fn apply_segment(
+        &self,
+        segment: &PathSegment,
+        value: Value,
+    ) -> Result<Value, PathError<M::AccessError>> {
+        match (segment, &value) {
+            (PathSegment::Member(field), Value::Struct { layout, address }) => {
+                self.read_member(layout, *address, field)
+            }
+            (
+                PathSegment::PointerMember(field),
+                Value::Pointer {
+                    pointee: LayoutType::Struct(layout),
+                    address,
+                },
+            ) => {
+                let address = self.dereference(segment, *address)?;
+                self.read_member(layout, address, field)
+            }
+            (
+                PathSegment::Index(index),
+                Value::Array {
+                    element,
+                    len,
+                    address,
+                },
+            ) => {
+                if index >= len {
+                    return Err(PathError::IndexOutOfBounds {
+                        index: *index,
+                        len: *len,
+                    });
+                }
+
+                self.read_element(element, *address, *index)
+            }
+            (PathSegment::Index(index), Value::Pointer { pointee, address }) => {
+                let address = self.dereference(segment, *address)?;
+                self.read_element(pointee, address, *index)
+            }
+            _ => Err(PathError::InvalidSegment {
+                segment: segment.clone(),
+                value,
+            }),
+        }
+    }
+
+    fn dereference(
+        &self,
+        segment: &PathSegment,
+        address: u64,
+    ) -> Result<u64, PathError<M::AccessError>> {
+        if address == 0 {
+            return Err(PathError::NullPointer(segment.clone()));
+        }
+
+        self.memory
+            .dereference(address)
+            .map_err(PathError::MemoryAccess)
+    }
+
+    fn read_member(
+        &self,
+        layout: &str,
+        address: u64,
+        field: &str,
+    ) -> Result<Value, PathError<M::AccessError>> {
+        let layout = self
+            .registry
+            .layout(layout)
+            .ok_or_else(|| PathError::UnknownLayout(layout.into()))?;
+
+        let field = layout.field(field).ok_or_else(|| PathError::UnknownField {
+            layout: layout.name().into(),
+            field: field.into(),
+        })?;
+
+        let field_address = address
+            .checked_add(field.offset)
+            .ok_or(PathError::AddressOverflow { address })?;
+
+        self.read_value(&field.field_type, field_address)
+    }
+
+    fn read_element(
+        &self,
+        element: &LayoutType,
+        address: u64,
+        index: usize,
+    ) -> Result<Value, PathError<M::AccessError>> {
+        let element_size = self
+            .registry
+            .size_of(element)
+            .ok_or_else(|| PathError::UnknownSize(element.clone()))?;
+
+        let element_address = (index as u64)
+            .checked_mul(element_size as u64)
+            .and_then(|offset| address.checked_add(offset))
+            .ok_or(PathError::AddressOverflow { address })?;
+
+        self.read_value(element, element_address)
+    }
+
+    /// Read a value of the given type located at `address`.
+    pub fn read_value(
+        &self,
+        value_type: &LayoutType,
+        address: u64,
+    ) -> Result<Value, PathError<M::AccessError>> {
+        Ok(match value_type {
+            LayoutType::Primitive(primitive) => self.read_primitive(*primitive, address)?,
+            LayoutType::Pointer {
+                pointee,
+                size,
+                resolve,
+            } => {
+                let raw = match size {
+                    4 => self.read_object::<u32>(address)? as u64,
+                    _ => self.read_object::<u64>(address)?,
+                };
+
+                Value::Pointer {
+                    pointee: pointee.as_ref().clone(),
+                    address: if raw == 0 { 0 } else { resolve.resolve(raw) },
+                }
+            }
+            LayoutType::Array(element, len) => Value::Array {
+                element: element.as_ref().clone(),
+                len: *len,
+                address,
+            },
+            LayoutType::Struct(layout) => Value::Struct {
+                layout: layout.clone(),
+                address,
+            },
+        })
+    }
+
+    fn read_primitive(
+        &self,
+        primitive: PrimitiveType,
+        address: u64,
+    ) -> Result<Value, PathError<M::AccessError>> {
+        Ok(match primitive {
+            PrimitiveType::U8 => Value::U8(self.read_object(address)?),
+            PrimitiveType::I8 => Value::I8(self.read_object(address)?),
+            PrimitiveType::U16 => Value::U16(self.read_object(address)?),
+            PrimitiveType::I16 => Value::I16(self.read_object(address)?),
+            PrimitiveType::U32 => Value::U32(self.read_object(address)?),
+            PrimitiveType::I32 => Value::I32(self.read_object(address)?),
+            PrimitiveType::U64 => Value::U64(self.read_object(address)?),
+            PrimitiveType::I64 => Value::I64(self.read_object(address)?),
+            PrimitiveType::F32 => Value::F32(self.read_object(address)?),
+            PrimitiveType::F64 => Value::F64(self.read_object(address)?),
+            PrimitiveType::Bool => Value::Bool(self.read_object(address)?),
+        })
+    }
+
+    fn read_object<T: FromMemoryView<DecodeError = Infallible>>(
+        &self,
+        address: u64,
+    ) -> Result<T, PathError<M::AccessError>> {
+        T::read_object(&self.memory, address)
+            .map_err(|err| PathError::MemoryAccess(err.into_access_error()))
+    }
+}
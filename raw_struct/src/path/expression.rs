@@ -0,0 +1,191 @@
+use alloc::{
+    string::String,
+    vec::Vec,
+};
+use core::fmt;
+
+/// The path expression could not be parsed.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct PathParseError {
+    /// Byte position within the expression.
+    pub position: usize,
+    pub message: &'static str,
+}
+
+impl fmt::Display for PathParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PathParseError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for PathParseError {}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PathSegment {
+    /// `.field`
+    Member(String),
+
+    /// `->field`
+    PointerMember(String),
+
+    /// `[index]`
+    Index(usize),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Member(name) => write!(f, ".{}", name),
+            Self::PointerMember(name) => write!(f, "->{}", name),
+            Self::Index(index) => write!(f, "[{}]", index),
+        }
+    }
+}
+
+/// A parsed path expression like `player->inventory.items[3]->name`.
+///
+/// The expression starts with the name of a root value followed by any number of
+/// member accesses (`.field`), pointer member accesses (`->field`) and indices (`[3]`, `[0x10]`).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PathExpression {
+    root: String,
+    segments: Vec<PathSegment>,
+}
+
+impl PathExpression {
+    pub fn parse(expression: &str) -> Result<Self, PathParseError> {
+        let mut parser = Parser {
+            input: expression,
+            position: 0,
+        };
+
+        parser.skip_whitespace();
+        let root = parser.identifier()?;
+
+        let mut segments = Vec::new();
+        loop {
+            parser.skip_whitespace();
+            if parser.is_empty() {
+                break;
+            }
+
+            if parser.consume("->") {
+                parser.skip_whitespace();
+                segments.push(PathSegment::PointerMember(parser.identifier()?));
+            } else if parser.consume(".") {
+                parser.skip_whitespace();
+                segments.push(PathSegment::Member(parser.identifier()?));
+            } else if parser.consume("[") {
+                parser.skip_whitespace();
+                let index = parser.integer()?;
+                parser.skip_whitespace();
+                if !parser.consume("]") {
+                    return Err(parser.error("expected ']'"));
+                }
+
+                segments.push(PathSegment::Index(index));
+            } else {
+                return Err(parser.error("expected '.', '->' or '['"));
+            }
+        }
+
+        Ok(Self { root, segments })
+    }
+
+    pub fn root(&self) -> &str {
+        &self.root
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+}
+
+impl fmt::Display for PathExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.root)?;
+        for segment in &self.segments {
+            segment.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn remaining(&self) -> &str {
+        &self.input[self.position..]
+    }
+
+    fn is_empty(&self) -> bool {
+        self.remaining().is_empty()
+    }
+
+    fn error(&self, message: &'static str) -> PathParseError {
+        PathParseError {
+            position: self.position,
+            message,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let remaining = self.remaining();
+        self.position += remaining.len() - remaining.trim_start().len();
+    }
+
+    fn consume(&mut self, token: &str) -> bool {
+        if self.remaining().starts_with(token) {
+            self.position += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &str {
+        let start = self.position;
+        let len = self
+            .remaining()
+            .find(|value: char| !predicate(value))
+            .unwrap_or(self.remaining().len());
+
+        self.position += len;
+        &self.input[start..self.position]
+    }
+
+    fn identifier(&mut self) -> Result<String, PathParseError> {
+        if !self
+            .remaining()
+            .starts_with(|value: char| value.is_ascii_alphabetic() || value == '_')
+        {
+            return Err(self.error("expected an identifier"));
+        }
+
+        Ok(self
+            .take_while(|value| value.is_ascii_alphanumeric() || value == '_')
+            .into())
+    }
+
+    fn integer(&mut self) -> Result<usize, PathParseError> {
+        let start = self.position;
+        let (digits, radix) = if self.consume("0x") || self.consume("0X") {
+            (self.take_while(|value| value.is_ascii_hexdigit()), 16)
+        } else {
+            (self.take_while(|value| value.is_ascii_digit()), 10)
+        };
+
+        usize::from_str_radix(digits, radix).map_err(|_| PathParseError {
+            position: start,
+            message: "expected an integer",
+        })
+    }
+}
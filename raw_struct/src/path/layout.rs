@@ -0,0 +1,351 @@
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    string::String,
+    vec::Vec,
+};
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{
+        Hash,
+        Hasher,
+    },
+};
+
+use crate::{
+    Describable,
+    PrimitiveType,
+    TypeInfo,
+    TypeKind,
+};
+
+/// The type of a field within a [`Layout`].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub enum LayoutType {
+    Primitive(PrimitiveType),
+
+    /// A pointer to `pointee` which is stored as `size` byte value.
+    /// The stored value is turned into the address by `resolve`.
+    Pointer {
+        pointee: Box<LayoutType>,
+        size: usize,
+        resolve: PointerResolver,
+    },
+
+    /// An inline array with a fixed number of elements.
+    Array(Box<LayoutType>, usize),
+
+    /// An inline struct described by the layout with the given name.
+    Struct(String),
+}
+
+impl LayoutType {
+    /// A 64 bit pointer which holds the address as is.
+    pub fn pointer(pointee: LayoutType) -> Self {
+        Self::pointer_with(pointee, 8, |raw| raw)
+    }
+
+    /// A pointer stored as `size` byte value which is turned into the address by `resolve`.
+    ///
+    /// # Panics
+    /// Panics if the size is neither 4 nor 8 bytes.
+    pub fn pointer_with(pointee: LayoutType, size: usize, resolve: fn(u64) -> u64) -> Self {
+        assert!(
+            size == 4 || size == 8,
+            "pointers must either be 4 or 8 bytes"
+        );
+
+        Self::Pointer {
+            pointee: Box::new(pointee),
+            size,
+            resolve: PointerResolver(resolve),
+        }
+    }
+
+    pub fn array(element: LayoutType, len: usize) -> Self {
+        Self::Array(Box::new(element), len)
+    }
+
+    pub fn structure(name: impl Into<String>) -> Self {
+        Self::Struct(name.into())
+    }
+
+    /// Convert generated type information into a layout type.
+    /// Returns `None` for opaque types.
+    ///
+    /// Pointers to slices are converted into pointers to the slice element.
+    pub fn from_type_info(type_info: &TypeInfo) -> Option<Self> {
+        Some(match type_info.kind {
+            TypeKind::Primitive(primitive) => Self::Primitive(primitive),
+            TypeKind::Pointer { pointee, resolve } => {
+                let size = type_info.size.filter(|size| matches!(size, 4 | 8))?;
+                let pointee = pointee();
+                let pointee = match pointee.kind {
+                    TypeKind::Array { element, len: None } => Self::from_type_info(&element())?,
+                    _ => Self::from_type_info(&pointee)?,
+                };
+
+                Self::pointer_with(pointee, size, resolve)
+            }
+            TypeKind::Array {
+                element,
+                len: Some(len),
+            } => Self::array(Self::from_type_info(&element())?, len),
+            TypeKind::Array { len: None, .. } => return None,
+            TypeKind::Struct { .. } => Self::structure(type_info.name),
+            TypeKind::Opaque => return None,
+        })
+    }
+}
+
+impl From<PrimitiveType> for LayoutType {
+    fn from(value: PrimitiveType) -> Self {
+        Self::Primitive(value)
+    }
+}
+
+impl fmt::Display for LayoutType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Primitive(primitive) => f.write_str(primitive.name()),
+            Self::Pointer { pointee, .. } => write!(f, "*{}", pointee),
+            Self::Array(element, len) => write!(f, "[{}; {}]", element, len),
+            Self::Struct(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Turns a stored pointer value into the address.
+///
+/// Functions can not be compared reliably, therefore all resolvers are considered equal.
+#[derive(Clone, Copy)]
+pub struct PointerResolver(pub fn(u64) -> u64);
+
+impl PointerResolver {
+    pub fn resolve(&self, raw: u64) -> u64 {
+        (self.0)(raw)
+    }
+}
+
+impl fmt::Debug for PointerResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PointerResolver")
+    }
+}
+
+impl PartialEq for PointerResolver {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for PointerResolver {}
+
+impl PartialOrd for PointerResolver {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PointerResolver {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for PointerResolver {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LayoutField {
+    pub name: String,
+    pub offset: u64,
+    pub field_type: LayoutType,
+}
+
+/// A runtime description of a struct.
+/// ```rust
+/// # use raw_struct::path::{Layout, LayoutType, PrimitiveType};
+/// let layout = Layout::new("Player")
+///     .with_size(0x20)
+///     .with_field("health", 0x00, PrimitiveType::U32)
+///     .with_field("inventory", 0x08, LayoutType::pointer(LayoutType::structure("Inventory")));
+///
+/// assert_eq!(layout.field("health").map(|field| field.offset), Some(0x00));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Layout {
+    name: String,
+    size: Option<usize>,
+    fields: Vec<LayoutField>,
+}
+
+impl Layout {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            size: None,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Set the size of the struct.
+    /// The size is required to index arrays of this struct.
+    pub fn with_size(mut self, size: usize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn with_field(
+        mut self,
+        name: impl Into<String>,
+        offset: u64,
+        field_type: impl Into<LayoutType>,
+    ) -> Self {
+        self.fields.push(LayoutField {
+            name: name.into(),
+            offset,
+            field_type: field_type.into(),
+        });
+        self
+    }
+
+    /// Create the layout of a struct based on its generated type information.
+    /// Inherited fields are included, fields with opaque types and chained fields are skipped.
+    ///
+    /// Returns `None` if the type is not a struct.
+    pub fn from_type_info(type_info: &TypeInfo) -> Option<Self> {
+        if !matches!(type_info.kind, TypeKind::Struct { .. }) {
+            return None;
+        }
+
+        let mut layout = Self::new(type_info.name);
+        layout.size = type_info.size;
+        layout.push_fields(type_info);
+        Some(layout)
+    }
+
+    fn push_fields(&mut self, type_info: &TypeInfo) {
+        if let Some(base) = type_info.base() {
+            self.push_fields(&base);
+        }
+
+        for field in type_info.fields().unwrap_or_default() {
+            if !field.chain().is_empty() {
+                continue;
+            }
+
+            if let Some(field_type) = LayoutType::from_type_info(&field.type_info()) {
+                self.fields.push(LayoutField {
+                    name: field.name().into(),
+                    offset: field.offset(),
+                    field_type,
+                });
+            }
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn size(&self) -> Option<usize> {
+        self.size
+    }
+
+    pub fn fields(&self) -> &[LayoutField] {
+        &self.fields
+    }
+
+    pub fn field(&self, name: &str) -> Option<&LayoutField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+/// A set of layouts, referenced by name from [`LayoutType::Struct`].
+#[derive(Debug, Default, Clone)]
+pub struct LayoutRegistry {
+    layouts: BTreeMap<String, Layout>,
+}
+
+impl LayoutRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a layout.
+    /// Returns the previous layout with the same name.
+    pub fn register(&mut self, layout: Layout) -> Option<Layout> {
+        self.layouts.insert(layout.name.clone(), layout)
+    }
+
+    /// Register the layout of `V` and all structs reachable from its fields.
+    /// Already registered layouts are kept.
+    /// ```rust
+    /// # use raw_struct::{raw_struct, builtins::Ptr64, path::LayoutRegistry};
+    /// #[raw_struct(size = 0x10)]
+    /// struct Node {
+    ///     #[field(offset = 0x00)]
+    ///     pub value: u32,
+    ///
+    ///     #[field(offset = 0x08)]
+    ///     pub next: Ptr64<Node>,
+    /// }
+    ///
+    /// let mut registry = LayoutRegistry::new();
+    /// registry.register_viewable::<Node>();
+    ///
+    /// let layout = registry.layout("Node").unwrap();
+    /// assert_eq!(layout.size(), Some(0x10));
+    /// assert_eq!(layout.fields().len(), 2);
+    /// ```
+    pub fn register_viewable<V: Describable + ?Sized>(&mut self) {
+        self.register_type_info(&V::type_info());
+    }
+
+    /// Register all struct layouts reachable from the type.
+    pub fn register_type_info(&mut self, type_info: &TypeInfo) {
+        match type_info.kind {
+            TypeKind::Pointer { pointee, .. } => self.register_type_info(&pointee()),
+            TypeKind::Array { element, .. } => self.register_type_info(&element()),
+            TypeKind::Struct { fields, base } => {
+                if self.layouts.contains_key(type_info.name) {
+                    return;
+                }
+
+                if let Some(layout) = Layout::from_type_info(type_info) {
+                    self.register(layout);
+                }
+
+                if let Some(base) = base {
+                    self.register_type_info(&base());
+                }
+
+                for field in fields() {
+                    self.register_type_info(&field.type_info());
+                }
+            }
+            TypeKind::Primitive(_) | TypeKind::Opaque => {}
+        }
+    }
+
+    pub fn layout(&self, name: &str) -> Option<&Layout> {
+        self.layouts.get(name)
+    }
+
+    pub fn layouts(&self) -> impl Iterator<Item = &Layout> {
+        self.layouts.values()
+    }
+
+    /// Size of a value of the given type or `None` if the size of a struct is unknown.
+    pub fn size_of(&self, value_type: &LayoutType) -> Option<usize> {
+        match value_type {
+            LayoutType::Primitive(primitive) => Some(primitive.size()),
+            LayoutType::Pointer { size, .. } => Some(*size),
+            LayoutType::Array(element, len) => Some(self.size_of(element)? * len),
+            LayoutType::Struct(name) => self.layout(name)?.size,
+        }
+    }
+}
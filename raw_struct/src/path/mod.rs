@@ -0,0 +1,30 @@
+//! Evaluate path expressions like `player->inventory.items[3]->name` at runtime.
+//!
+//! Paths are resolved against runtime [`Layout`]s, which are registered within a [`LayoutRegistry`].
+
+mod evaluator;
+pub use evaluator::{
+    PathError,
+    PathEvaluator,
+};
+
+mod expression;
+pub use expression::{
+    PathExpression,
+    PathParseError,
+    PathSegment,
+};
+
+mod layout;
+pub use layout::{
+    Layout,
+    LayoutField,
+    LayoutRegistry,
+    LayoutType,
+    PointerResolver,
+};
+
+pub use crate::PrimitiveType;
+
+mod value;
+pub use value::Value;
@@ -0,0 +1,121 @@
+use alloc::string::String;
+use core::fmt;
+
+use super::LayoutType;
+
+/// A dynamically typed value produced by evaluating a path expression.
+///
+/// Primitives are read eagerly, while pointers, arrays and structs only carry
+/// their address and are resolved by further path segments.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Value {
+    U8(u8),
+    I8(i8),
+    U16(u16),
+    I16(i16),
+    U32(u32),
+    I32(i32),
+    U64(u64),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+    Bool(bool),
+
+    /// A pointer which has not yet been dereferenced.
+    /// `address` is the resolved target address (e.g. decompressed or with tag bits removed)
+    /// and not the raw value stored in memory. Null pointers resolve to zero.
+    Pointer {
+        pointee: LayoutType,
+        address: u64,
+    },
+
+    /// An inline array located at `address`.
+    Array {
+        element: LayoutType,
+        len: usize,
+        address: u64,
+    },
+
+    /// A struct located at `address`.
+    Struct {
+        layout: String,
+        address: u64,
+    },
+}
+
+impl Value {
+    /// A struct with the given layout name located at `address`.
+    pub fn structure(layout: impl Into<String>, address: u64) -> Self {
+        Self::Struct {
+            layout: layout.into(),
+            address,
+        }
+    }
+
+    /// A pointer to a struct with the given layout name.
+    pub fn struct_pointer(layout: impl Into<String>, address: u64) -> Self {
+        Self::Pointer {
+            pointee: LayoutType::Struct(layout.into()),
+            address,
+        }
+    }
+
+    /// The value as an unsigned integer if it is an integer, boolean or pointer.
+    pub fn as_u64(&self) -> Option<u64> {
+        Some(match *self {
+            Self::U8(value) => value as u64,
+            Self::I8(value) => value as u64,
+            Self::U16(value) => value as u64,
+            Self::I16(value) => value as u64,
+            Self::U32(value) => value as u64,
+            Self::I32(value) => value as u64,
+            Self::U64(value) => value,
+            Self::I64(value) => value as u64,
+            Self::Bool(value) => value as u64,
+            Self::Pointer { address, .. } => address,
+            _ => return None,
+        })
+    }
+
+    /// The value as a float if it is a number.
+    pub fn as_f64(&self) -> Option<f64> {
+        Some(match *self {
+            Self::F32(value) => value as f64,
+            Self::F64(value) => value,
+            Self::I8(value) => value as f64,
+            Self::I16(value) => value as f64,
+            Self::I32(value) => value as f64,
+            Self::I64(value) => value as f64,
+            Self::U8(value) => value as f64,
+            Self::U16(value) => value as f64,
+            Self::U32(value) => value as f64,
+            Self::U64(value) => value as f64,
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::U8(value) => value.fmt(f),
+            Self::I8(value) => value.fmt(f),
+            Self::U16(value) => value.fmt(f),
+            Self::I16(value) => value.fmt(f),
+            Self::U32(value) => value.fmt(f),
+            Self::I32(value) => value.fmt(f),
+            Self::U64(value) => value.fmt(f),
+            Self::I64(value) => value.fmt(f),
+            Self::F32(value) => value.fmt(f),
+            Self::F64(value) => value.fmt(f),
+            Self::Bool(value) => value.fmt(f),
+            Self::Pointer { pointee, address } => write!(f, "*{} 0x{:X}", pointee, address),
+            Self::Array {
+                element,
+                len,
+                address,
+            } => write!(f, "[{}; {}] @ 0x{:X}", element, len, address),
+            Self::Struct { layout, address } => write!(f, "{} @ 0x{:X}", layout, address),
+        }
+    }
+}
@@ -2,17 +2,34 @@
 use alloc::vec::Vec;
 use core::{
     self,
+    convert::Infallible,
     marker::PhantomData,
     mem,
 };
 
 use crate::{
-    builtins::Ptr64,
+    batch::FieldBatch,
+    builtins::{
+        chain,
+        CompressedPtr32,
+        Handle,
+        HandleResolver,
+        Pointer,
+        PointerCompression,
+        PtrChainError,
+    },
     memory::{
         MemoryView,
         MemoryViewDereferenceable,
     },
-    view::ViewableField,
+    view::{
+        ChainedViewableField,
+        ViewableField,
+    },
+    views::{
+        WindowAccessError,
+        WindowMemory,
+    },
     Copy,
     CopyConstructable,
     FromMemoryView,
@@ -24,13 +41,15 @@ use crate::{
 };
 
 /// A reference to an object living in the underlying memory view.
-pub struct Reference<V: ?Sized, M: MemoryView> {
+///
+/// The memory view is either a [`MemoryView`] or an [`AsyncMemoryView`](crate::AsyncMemoryView).
+pub struct Reference<V: ?Sized, M> {
     memory: M,
     memory_offset: u64,
     _type: PhantomData<V>,
 }
 
-impl<T: ?Sized, M: MemoryView> Reference<T, M> {
+impl<T: ?Sized, M> Reference<T, M> {
     pub fn new(memory: M, address: u64) -> Self {
         Self {
             memory,
@@ -47,6 +66,10 @@ impl<T: ?Sized, M: MemoryView> Reference<T, M> {
         &self.memory
     }
 
+    pub(crate) fn memory_mut(&mut self) -> &mut M {
+        &mut self.memory
+    }
+
     pub fn cast<V: ?Sized>(self) -> Reference<V, M> {
         Reference {
             memory: self.memory,
@@ -56,7 +79,7 @@ impl<T: ?Sized, M: MemoryView> Reference<T, M> {
     }
 }
 
-impl<T: ?Sized, M: MemoryView + Clone> Clone for Reference<T, M> {
+impl<T: ?Sized, M: Clone> Clone for Reference<T, M> {
     fn clone(&self) -> Self {
         Self {
             memory: self.memory.clone(),
@@ -77,6 +100,39 @@ impl<T: Viewable, M: MemoryView> Reference<T, M> {
         R::read_object(&self.memory, self.memory_offset + field.offset())
     }
 
+    /// Read multiple fields with a single batched memory read.
+    /// ```rust
+    /// # use raw_struct::{raw_struct, Reference};
+    /// # #[raw_struct(size = 0x10)]
+    /// # struct MyStruct {
+    /// #     #[field(offset = 0x00)]
+    /// #     pub field_a: u32,
+    /// #
+    /// #     #[field(offset = 0x08)]
+    /// #     pub field_b: u64,
+    /// # }
+    /// let memory = [0u8; 0x10];
+    /// let object = Reference::<MyStruct, _>::new(memory.as_slice(), 0x00);
+    ///
+    /// let (field_a, field_b) = object.read_fields((MyStruct::field_a, MyStruct::field_b))?;
+    /// # Ok::<(), raw_struct::OutOfBoundsViolation>(())
+    /// ```
+    pub fn read_fields<B: FieldBatch<T>>(&self, fields: B) -> Result<B::Values, M::AccessError> {
+        fields.read_batch(&self.memory, self.memory_offset)
+    }
+
+    /// Resolve the handle stored within `field`.
+    /// Returns `None` if the handle is invalid.
+    pub fn resolve_field<R: ?Sized, H: HandleResolver<R>, C>(
+        &self,
+        field: &TypedViewableField<C, Handle<R, H>>,
+    ) -> Result<Option<Reference<R, &M>>, M::AccessError>
+    where
+        T: ViewableExtends<C>,
+    {
+        self.reference_field(field).resolve()
+    }
+
     pub fn reference_field<R, C>(&self, field: &TypedViewableField<C, R>) -> Reference<R, &M>
     where
         T: ViewableExtends<C>,
@@ -85,14 +141,50 @@ impl<T: Viewable, M: MemoryView> Reference<T, M> {
     }
 }
 
+impl<T: Viewable, M: MemoryViewDereferenceable> Reference<T, M> {
+    /// Follow the pointer chain of `field` and read the value at its end.
+    pub fn read_chain<R: FromMemoryView, C>(
+        &self,
+        field: &ChainedViewableField<C, R>,
+    ) -> Result<R, PtrChainError<M::AccessError, R::DecodeError>>
+    where
+        T: ViewableExtends<C>,
+    {
+        chain::read_chain(
+            &self.memory,
+            self.memory_offset,
+            Some(field.offset()),
+            field.chain(),
+        )
+    }
+
+    /// Follow the pointer chain of `field` and reference the value at its end.
+    pub fn reference_chain<R, C>(
+        &self,
+        field: &ChainedViewableField<C, R>,
+    ) -> Result<Reference<R, &M>, PtrChainError<M::AccessError, Infallible>>
+    where
+        T: ViewableExtends<C>,
+    {
+        let address = chain::resolve_chain(
+            &self.memory,
+            self.memory_offset,
+            Some(field.offset()),
+            field.chain(),
+        )?;
+
+        Ok(Reference::new(&self.memory, address))
+    }
+}
+
 impl<T: Viewable, M: MemoryView> Reference<T, M>
 where
     for<'a> &'a M: MemoryViewDereferenceable,
 {
-    pub fn dereference_field<R: ?Sized, C>(
+    pub fn dereference_field<R: Pointer, C>(
         &self,
-        field: &TypedViewableField<C, Ptr64<R>>,
-    ) -> Result<Reference<R, &M>, <&M as MemoryView>::AccessError>
+        field: &TypedViewableField<C, R>,
+    ) -> Result<Reference<R::Target, &M>, <&M as MemoryView>::AccessError>
     where
         T: ViewableExtends<C>,
     {
@@ -100,14 +192,95 @@ where
     }
 }
 
-impl<T: ?Sized, M: MemoryViewDereferenceable> Reference<Ptr64<T>, M> {
-    pub fn dereference(self) -> Result<Reference<T, M>, M::AccessError> {
+impl<R: Pointer, M: MemoryViewDereferenceable> Reference<R, M> {
+    pub fn dereference(self) -> Result<Reference<R::Target, M>, M::AccessError> {
         let ptr_value = self.read().map_err(|err| err.into_access_error())?;
-        let memory_offset = self.memory.dereference(ptr_value.address())?;
+        let memory_offset = self.memory.dereference(ptr_value.target_address())?;
         Ok(Reference::new(self.memory, memory_offset))
     }
 }
 
+impl<T: ?Sized, M: MemoryView> Reference<T, WindowMemory<M>> {
+    /// Remove the bounds of a reference created by [`Reference::bounded`].
+    pub fn unbounded(self) -> Reference<T, M> {
+        Reference::new(self.memory.into_inner(), self.memory_offset)
+    }
+}
+
+impl<T: Viewable, M: MemoryViewDereferenceable> Reference<T, WindowMemory<M>> {
+    /// Dereference the pointer stored within `field`.
+    /// Only the pointer itself is read through the window, the target is referenced within the underlying memory.
+    pub fn dereference_field<R: Pointer, C>(
+        &self,
+        field: &TypedViewableField<C, R>,
+    ) -> Result<Reference<R::Target, &M>, WindowAccessError<M::AccessError>>
+    where
+        T: ViewableExtends<C>,
+    {
+        self.reference_field(field).dereference()
+    }
+}
+
+impl<'a, T: Viewable, M: MemoryViewDereferenceable> Reference<T, &'a WindowMemory<M>> {
+    /// Dereference the pointer stored within `field`.
+    /// Only the pointer itself is read through the window, the target is referenced within the underlying memory.
+    pub fn dereference_field<R: Pointer, C>(
+        &self,
+        field: &TypedViewableField<C, R>,
+    ) -> Result<Reference<R::Target, &'a M>, WindowAccessError<M::AccessError>>
+    where
+        T: ViewableExtends<C>,
+    {
+        Reference::<R, _>::new(self.memory, self.memory_offset + field.offset()).dereference()
+    }
+}
+
+impl<R: Pointer, M: MemoryViewDereferenceable> Reference<R, WindowMemory<M>> {
+    /// Dereference the pointer and drop the window, as the target usually lives outside of it.
+    pub fn dereference(self) -> Result<Reference<R::Target, M>, WindowAccessError<M::AccessError>> {
+        let ptr_value = self.read().map_err(|err| err.into_access_error())?;
+        let memory = self.memory.into_inner();
+        let memory_offset = memory
+            .dereference(ptr_value.target_address())
+            .map_err(WindowAccessError::MemoryAccess)?;
+
+        Ok(Reference::new(memory, memory_offset))
+    }
+}
+
+impl<'a, R: Pointer, M: MemoryViewDereferenceable> Reference<R, &'a WindowMemory<M>> {
+    /// Dereference the pointer and drop the window, as the target usually lives outside of it.
+    pub fn dereference(
+        self,
+    ) -> Result<Reference<R::Target, &'a M>, WindowAccessError<M::AccessError>> {
+        let ptr_value = self.read().map_err(|err| err.into_access_error())?;
+        let memory = self.memory.memory();
+        let memory_offset = memory
+            .dereference(ptr_value.target_address())
+            .map_err(WindowAccessError::MemoryAccess)?;
+
+        Ok(Reference::new(memory, memory_offset))
+    }
+}
+
+impl<T: ?Sized, B: PointerCompression, M: MemoryViewDereferenceable>
+    Reference<CompressedPtr32<T, B>, M>
+{
+    /// Dereference the compressed pointer using the decompression context `context`.
+    pub fn dereference_in(self, context: &B) -> Result<Reference<T, M>, M::AccessError> {
+        let ptr_value = self.read().map_err(|err| err.into_access_error())?;
+        let memory_offset = self.memory.dereference(ptr_value.address_in(context))?;
+        Ok(Reference::new(self.memory, memory_offset))
+    }
+}
+
+impl<T: ?Sized, H: HandleResolver<T>, M: MemoryView> Reference<Handle<T, H>, M> {
+    pub fn resolve(self) -> Result<Option<Reference<T, M>>, M::AccessError> {
+        let handle = self.read().map_err(|err| err.into_access_error())?;
+        handle.resolve(self.memory)
+    }
+}
+
 impl<T: FromMemoryView, M: MemoryView> Reference<T, M> {
     pub fn read(&self) -> Result<T, MemoryDecodeError<M::AccessError, T::DecodeError>> {
         T::read_object(&self.memory, self.memory_offset)
@@ -115,6 +288,16 @@ impl<T: FromMemoryView, M: MemoryView> Reference<T, M> {
 }
 
 impl<T: ViewableSized, M: MemoryView> Reference<T, M> {
+    /// Restrict all reads through this reference to the memory of the referenced object.
+    /// Reading fields exceeding [`ViewableSized::memory_size`] will fail.
+    pub fn bounded(self) -> Reference<T, WindowMemory<M>> {
+        let memory_size = T::memory_size() as u64;
+        Reference::new(
+            self.memory.window(self.memory_offset, memory_size),
+            self.memory_offset,
+        )
+    }
+
     pub fn create_copy(&self) -> Result<Copy<T>, M::AccessError> {
         Copy::read_from_memory(&self.memory, self.memory_offset)
     }
@@ -144,6 +327,10 @@ impl<T, M: MemoryView, const N: usize> Reference<[T; N], M> {
     pub fn len(&self) -> usize {
         N
     }
+
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
 }
 
 impl<T: CopyConstructable, M: MemoryView, const N: usize> Reference<[T; N], M> {
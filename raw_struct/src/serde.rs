@@ -0,0 +1,436 @@
+//! Serialization of objects using the generated field metadata.
+//!
+//! Objects are serialized as a map of field name to decoded value:
+//! - primitives are serialized as is, values which could not be read as `None`
+//! - inline structs are serialized as nested maps
+//! - arrays are serialized as sequences, slices of unknown length as `None`
+//! - pointers are serialized as their address, or `None` if null.
+//!   Pointers to structs can be followed up to a given depth, see [`SerializeFields::follow_pointers`].
+//! - fields without type information and chained fields are skipped
+//!
+//! [`Copy`] serializes as a map of its fields. Use [`bytes`] to serialize it as raw bytes instead,
+//! which supports deserialization as well.
+use ::serde::{
+    ser::{
+        SerializeMap,
+        SerializeSeq,
+    },
+    Serialize,
+    Serializer,
+};
+
+use crate::{
+    debug::DereferenceFn,
+    ArrayValue,
+    Copy,
+    Describable,
+    FieldVisitor,
+    MemoryView,
+    MemoryViewDereferenceable,
+    PointerValue,
+    Reference,
+    StructValue,
+    Viewable,
+    ViewableField,
+    ViewableSized,
+};
+
+/// Serializes all fields of an object as a map.
+/// ```rust
+/// # use raw_struct::{raw_struct, Reference};
+/// #[raw_struct(size = 0x08)]
+/// struct MyStruct {
+///     #[field(offset = 0x00)]
+///     pub field_a: u32,
+///
+///     #[field(offset = 0x04)]
+///     pub field_b: [u8; 2],
+/// }
+///
+/// let memory = [0x01u8, 0x00, 0x00, 0x00, 0x02, 0x03];
+/// let object = Reference::<MyStruct, _>::new(memory.as_slice(), 0x00);
+/// assert_eq!(
+///     serde_json::to_string(&object.serialize_fields()).unwrap(),
+///     r#"{"field_a":1,"field_b":[2,3]}"#
+/// );
+/// ```
+pub struct SerializeFields<'a, M: MemoryView> {
+    value: StructValue<'a, M>,
+    depth: usize,
+    dereference: Option<DereferenceFn<M>>,
+}
+
+impl<'a, M: MemoryView> SerializeFields<'a, M> {
+    pub fn new(value: StructValue<'a, M>) -> Self {
+        Self {
+            value,
+            depth: 0,
+            dereference: None,
+        }
+    }
+
+    fn nested<'b>(&self, value: StructValue<'b, M>, depth: usize) -> SerializeFields<'b, M> {
+        SerializeFields {
+            value,
+            depth,
+            dereference: self.dereference,
+        }
+    }
+}
+
+impl<M: MemoryViewDereferenceable> SerializeFields<'_, M> {
+    /// Follow pointers to structs and serialize the pointed to object up to `depth` levels deep.
+    pub fn follow_pointers(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self.dereference = Some(M::dereference);
+        self
+    }
+}
+
+impl<M: MemoryView> Serialize for SerializeFields<'_, M> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut counter = FieldCounter(0);
+        self.value.visit_fields(&mut counter);
+
+        let mut serializer = FieldSerializer {
+            options: self,
+            sink: MapSink(serializer.serialize_map(Some(counter.0))?),
+            error: None,
+        };
+        self.value.visit_fields(&mut serializer);
+
+        match serializer.error {
+            Some(error) => Err(error),
+            None => serializer.sink.0.end(),
+        }
+    }
+}
+
+/// Counts the fields which will be serialized.
+struct FieldCounter(usize);
+
+impl<M: MemoryView> FieldVisitor<M> for FieldCounter {
+    fn visit_field(&mut self, _field: &dyn ViewableField) {
+        self.0 += 1;
+    }
+
+    fn visit_opaque(&mut self, _field: &dyn ViewableField) {}
+}
+
+trait Sink {
+    type Error;
+
+    fn push<T: Serialize + ?Sized>(
+        &mut self,
+        field: &dyn ViewableField,
+        value: &T,
+    ) -> Result<(), Self::Error>;
+
+    fn skip(&mut self) -> Result<(), Self::Error>;
+}
+
+struct MapSink<S>(S);
+
+impl<S: SerializeMap> Sink for MapSink<S> {
+    type Error = S::Error;
+
+    fn push<T: Serialize + ?Sized>(
+        &mut self,
+        field: &dyn ViewableField,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_entry(field.name(), value)
+    }
+
+    fn skip(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+struct SeqSink<S>(S);
+
+impl<S: SerializeSeq> Sink for SeqSink<S> {
+    type Error = S::Error;
+
+    fn push<T: Serialize + ?Sized>(
+        &mut self,
+        _field: &dyn ViewableField,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.0.serialize_element(value)
+    }
+
+    fn skip(&mut self) -> Result<(), Self::Error> {
+        /* keep the element indices intact */
+        self.0.serialize_element(&None::<()>)
+    }
+}
+
+struct FieldSerializer<'o, 'a, M: MemoryView, K: Sink> {
+    options: &'o SerializeFields<'a, M>,
+    sink: K,
+    error: Option<K::Error>,
+}
+
+impl<M: MemoryView, K: Sink> FieldSerializer<'_, '_, M, K> {
+    fn push<T: Serialize + ?Sized>(&mut self, field: &dyn ViewableField, value: &T) {
+        if self.error.is_none() {
+            self.error = self.sink.push(field, value).err();
+        }
+    }
+
+    fn push_result<T: Serialize>(
+        &mut self,
+        field: &dyn ViewableField,
+        value: Result<T, M::AccessError>,
+    ) {
+        self.push(field, &value.ok())
+    }
+}
+
+impl<M: MemoryView, K: Sink> FieldVisitor<M> for FieldSerializer<'_, '_, M, K> {
+    fn visit_u8(&mut self, field: &dyn ViewableField, value: Result<u8, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_i8(&mut self, field: &dyn ViewableField, value: Result<i8, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_u16(&mut self, field: &dyn ViewableField, value: Result<u16, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_i16(&mut self, field: &dyn ViewableField, value: Result<i16, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_u32(&mut self, field: &dyn ViewableField, value: Result<u32, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_i32(&mut self, field: &dyn ViewableField, value: Result<i32, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_u64(&mut self, field: &dyn ViewableField, value: Result<u64, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_i64(&mut self, field: &dyn ViewableField, value: Result<i64, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_f32(&mut self, field: &dyn ViewableField, value: Result<f32, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_f64(&mut self, field: &dyn ViewableField, value: Result<f64, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_bool(&mut self, field: &dyn ViewableField, value: Result<bool, M::AccessError>) {
+        self.push_result(field, value)
+    }
+
+    fn visit_ptr(
+        &mut self,
+        field: &dyn ViewableField,
+        value: Result<PointerValue<'_, M>, M::AccessError>,
+    ) {
+        match value {
+            Ok(value) => {
+                let options = self.options;
+                self.push(field, &PointerField { options, value })
+            }
+            Err(_) => self.push(field, &None::<()>),
+        }
+    }
+
+    fn visit_struct(&mut self, field: &dyn ViewableField, value: StructValue<'_, M>) {
+        let options = self.options;
+        self.push(field, &options.nested(value, options.depth))
+    }
+
+    fn visit_array(&mut self, field: &dyn ViewableField, value: ArrayValue<'_, M>) {
+        let options = self.options;
+        self.push(field, &ArrayField { options, value })
+    }
+
+    fn visit_opaque(&mut self, _field: &dyn ViewableField) {
+        if self.error.is_none() {
+            self.error = self.sink.skip().err();
+        }
+    }
+}
+
+struct PointerField<'o, 'a, 'v, M: MemoryView> {
+    options: &'o SerializeFields<'a, M>,
+    value: PointerValue<'v, M>,
+}
+
+impl<M: MemoryView> Serialize for PointerField<'_, '_, '_, M> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (options, value) = (self.options, &self.value);
+        if value.is_null() {
+            return serializer.serialize_none();
+        }
+
+        let dereference = options
+            .dereference
+            .filter(|_| options.depth > 0 && value.pointee().fields().is_some());
+
+        let Some(dereference) = dereference else {
+            return serializer.serialize_u64(value.address());
+        };
+
+        match dereference(value.memory(), value.address()) {
+            Ok(address) => options
+                .nested(
+                    StructValue::new(value.memory(), address, value.pointee()),
+                    options.depth - 1,
+                )
+                .serialize(serializer),
+            Err(_) => serializer.serialize_none(),
+        }
+    }
+}
+
+struct ArrayField<'o, 'a, 'v, M: MemoryView> {
+    options: &'o SerializeFields<'a, M>,
+    value: ArrayValue<'v, M>,
+}
+
+impl<M: MemoryView> Serialize for ArrayField<'_, '_, '_, M> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Some(len) = self.value.len() else {
+            return serializer.serialize_none();
+        };
+
+        let mut serializer = FieldSerializer {
+            options: self.options,
+            sink: SeqSink(serializer.serialize_seq(Some(len))?),
+            error: None,
+        };
+        for index in 0..len {
+            self.value.visit_element(index, &mut serializer);
+        }
+
+        match serializer.error {
+            Some(error) => Err(error),
+            None => serializer.sink.0.end(),
+        }
+    }
+}
+
+impl<T: Viewable + Describable, M: MemoryView> Reference<T, M> {
+    /// Serialize all fields of the referenced object.
+    /// See [`SerializeFields`] for more details.
+    pub fn serialize_fields(&self) -> SerializeFields<'_, M> {
+        SerializeFields::new(StructValue::new(
+            self.memory(),
+            self.memory_address(),
+            T::type_info(),
+        ))
+    }
+}
+
+impl<T: ViewableSized + Describable> Serialize for Copy<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize_fields().serialize(serializer)
+    }
+}
+
+/// Serialize [`Copy`] as its raw bytes.
+///
+/// Use with `#[serde(with = "raw_struct::serde::bytes")]`.
+/// ```rust
+/// # use raw_struct::{raw_struct, Copy};
+/// # use serde::{Deserialize, Serialize};
+/// #[raw_struct(size = 0x04)]
+/// struct MyStruct {
+///     #[field(offset = 0x00)]
+///     pub field_a: u32,
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Snapshot {
+///     #[serde(with = "raw_struct::serde::bytes")]
+///     object: Copy<MyStruct>,
+/// }
+///
+/// let snapshot = Snapshot { object: Copy::new([0x01, 0x00, 0x00, 0x00]) };
+/// let json = serde_json::to_string(&snapshot).unwrap();
+/// assert_eq!(json, r#"{"object":[1,0,0,0]}"#);
+///
+/// let snapshot = serde_json::from_str::<Snapshot>(&json).unwrap();
+/// assert_eq!(snapshot.object.read_field(MyStruct::field_a).unwrap(), 1);
+/// ```
+pub mod bytes {
+    use alloc::vec::Vec;
+    use core::{
+        fmt,
+        marker::PhantomData,
+    };
+
+    use ::serde::{
+        de::{
+            self,
+            SeqAccess,
+            Visitor,
+        },
+        Deserializer,
+        Serializer,
+    };
+
+    use crate::{
+        Copy,
+        ViewableSized,
+    };
+
+    pub fn serialize<T: ViewableSized, S: Serializer>(
+        value: &Copy<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&value.to_bytes())
+    }
+
+    pub fn deserialize<'de, T: ViewableSized, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Copy<T>, D::Error> {
+        deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+    }
+
+    struct BytesVisitor<T>(PhantomData<T>);
+
+    impl<T: ViewableSized> BytesVisitor<T> {
+        fn create<E: de::Error>(&self, bytes: &[u8]) -> Result<Copy<T>, E> {
+            if bytes.len() != T::memory_size() {
+                return Err(E::invalid_length(bytes.len(), self));
+            }
+
+            Copy::read_from_memory(&bytes, 0x00).map_err(E::custom)
+        }
+    }
+
+    impl<'de, T: ViewableSized> Visitor<'de> for BytesVisitor<T> {
+        type Value = Copy<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "{} bytes", T::memory_size())
+        }
+
+        fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+            self.create(value)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = Vec::with_capacity(T::memory_size());
+            while let Some(byte) = seq.next_element::<u8>()? {
+                bytes.push(byte);
+            }
+
+            self.create(&bytes)
+        }
+    }
+}
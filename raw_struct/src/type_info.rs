@@ -0,0 +1,327 @@
+use core::{
+    any,
+    marker::PhantomData,
+    mem,
+};
+
+use crate::{
+    builtins::{
+        CompressedPtr32,
+        PointerCompression,
+        PointerPolicy,
+        Ptr64,
+    },
+    Copy,
+    Viewable,
+    ViewableField,
+    ViewableSized,
+};
+
+/// Primitive types with a well known memory representation.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum PrimitiveType {
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    F32,
+    F64,
+    Bool,
+}
+
+impl PrimitiveType {
+    pub const fn size(&self) -> usize {
+        match self {
+            Self::U8 | Self::I8 | Self::Bool => 1,
+            Self::U16 | Self::I16 => 2,
+            Self::U32 | Self::I32 | Self::F32 => 4,
+            Self::U64 | Self::I64 | Self::F64 => 8,
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::U8 => "u8",
+            Self::I8 => "i8",
+            Self::U16 => "u16",
+            Self::I16 => "i16",
+            Self::U32 => "u32",
+            Self::I32 => "i32",
+            Self::U64 => "u64",
+            Self::I64 => "i64",
+            Self::F32 => "f32",
+            Self::F64 => "f64",
+            Self::Bool => "bool",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum TypeKind {
+    Primitive(PrimitiveType),
+
+    /// A pointer to another type, e.g. [`Ptr64`] or [`CompressedPtr32`].
+    /// The size of the type is the size of the raw pointer value.
+    Pointer {
+        pointee: fn() -> TypeInfo,
+
+        /// Turn the raw pointer value into the address it points to.
+        resolve: fn(u64) -> u64,
+    },
+
+    /// An array of elements.
+    /// The length of slices is unknown.
+    Array {
+        element: fn() -> TypeInfo,
+        len: Option<usize>,
+    },
+
+    /// A nested [`Viewable`].
+    Struct {
+        fields: fn() -> &'static [&'static dyn ViewableField],
+
+        /// The type this struct inherits from.
+        base: Option<fn() -> TypeInfo>,
+    },
+
+    /// Any other type without further type information.
+    Opaque,
+}
+
+/// Runtime type information of a field.
+#[derive(Debug, Clone, Copy)]
+pub struct TypeInfo {
+    pub name: &'static str,
+
+    /// The size of the type in bytes if known.
+    pub size: Option<usize>,
+
+    pub kind: TypeKind,
+}
+
+impl TypeInfo {
+    pub const fn primitive(primitive: PrimitiveType) -> Self {
+        Self {
+            name: primitive.name(),
+            size: Some(primitive.size()),
+            kind: TypeKind::Primitive(primitive),
+        }
+    }
+
+    /// Type information of a [`Viewable`] struct.
+    pub fn structure<V: Viewable + ?Sized>(size: Option<usize>) -> Self {
+        Self {
+            name: V::name(),
+            size,
+            kind: TypeKind::Struct {
+                fields: V::fields,
+                base: None,
+            },
+        }
+    }
+
+    /// Set the base type of a struct.
+    pub fn with_base(mut self, base_type: fn() -> TypeInfo) -> Self {
+        if let TypeKind::Struct { base, .. } = &mut self.kind {
+            *base = Some(base_type);
+        }
+
+        self
+    }
+
+    /// Type information of a field whose type is not known.
+    pub const fn unknown() -> Self {
+        Self {
+            name: "<unknown>",
+            size: None,
+            kind: TypeKind::Opaque,
+        }
+    }
+
+    /// Type information of a type which does not implement [`Describable`].
+    pub fn opaque<T>() -> Self {
+        Self {
+            name: any::type_name::<T>(),
+            size: Some(mem::size_of::<T>()),
+            kind: TypeKind::Opaque,
+        }
+    }
+
+    /// Type information of the pointer type `P` pointing to `pointee`.
+    pub fn pointer<P>(pointee: fn() -> TypeInfo, resolve: fn(u64) -> u64) -> Self {
+        Self {
+            name: any::type_name::<P>(),
+            size: Some(mem::size_of::<P>()),
+            kind: TypeKind::Pointer { pointee, resolve },
+        }
+    }
+
+    /// Fields of a nested struct or the fields of the struct pointed to / contained in the array.
+    /// Inherited fields are not included.
+    pub fn fields(&self) -> Option<&'static [&'static dyn ViewableField]> {
+        match self.kind {
+            TypeKind::Struct { fields, .. } => Some(fields()),
+            TypeKind::Pointer {
+                pointee: target, ..
+            }
+            | TypeKind::Array {
+                element: target, ..
+            } => match target().kind {
+                TypeKind::Struct { fields, .. } => Some(fields()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Type information of the base type of a struct.
+    pub fn base(&self) -> Option<TypeInfo> {
+        match self.kind {
+            TypeKind::Struct {
+                base: Some(base), ..
+            } => Some(base()),
+            _ => None,
+        }
+    }
+}
+
+/// Types which can describe themself at runtime.
+///
+/// Implemented for all primitives, pointers, arrays and types generated by `#[raw_struct]`.
+/// Field types not implementing this trait are described as [`TypeKind::Opaque`].
+pub trait Describable {
+    fn type_info() -> TypeInfo;
+}
+
+macro_rules! impl_describable_primitive {
+    ($($ty:ty => $primitive:ident),*) => {
+        $(
+            impl Describable for $ty {
+                fn type_info() -> TypeInfo {
+                    TypeInfo::primitive(PrimitiveType::$primitive)
+                }
+            }
+        )*
+    };
+}
+
+impl_describable_primitive!(
+    u8 => U8, i8 => I8,
+    u16 => U16, i16 => I16,
+    u32 => U32, i32 => I32,
+    u64 => U64, i64 => I64,
+    f32 => F32, f64 => F64,
+    bool => Bool
+);
+
+impl<T: Describable, const N: usize> Describable for [T; N] {
+    fn type_info() -> TypeInfo {
+        TypeInfo {
+            name: any::type_name::<Self>(),
+            size: T::type_info().size.map(|size| size * N),
+            kind: TypeKind::Array {
+                element: T::type_info,
+                len: Some(N),
+            },
+        }
+    }
+}
+
+impl<T: Describable> Describable for [T] {
+    fn type_info() -> TypeInfo {
+        TypeInfo {
+            name: any::type_name::<Self>(),
+            size: None,
+            kind: TypeKind::Array {
+                element: T::type_info,
+                len: None,
+            },
+        }
+    }
+}
+
+impl<T: ?Sized + Describable, P: PointerPolicy> Describable for Ptr64<T, P> {
+    fn type_info() -> TypeInfo {
+        TypeInfo::pointer::<Self>(T::type_info, P::canonicalize)
+    }
+}
+
+impl<T: ?Sized + Describable, B: PointerCompression + Default> Describable
+    for CompressedPtr32<T, B>
+{
+    fn type_info() -> TypeInfo {
+        TypeInfo::pointer::<Self>(T::type_info, resolve_compressed::<T, B>)
+    }
+}
+
+fn resolve_compressed<T: ?Sized, B: PointerCompression + Default>(raw: u64) -> u64 {
+    CompressedPtr32::<T, B>::from_value(raw as u32).address()
+}
+
+impl<V: ViewableSized + Describable> Describable for Copy<V> {
+    fn type_info() -> TypeInfo {
+        V::type_info()
+    }
+}
+
+/// Resolves the [`TypeInfo`] of field types within `#[raw_struct]`.
+/// Types implementing [`Describable`] are described by their implementation,
+/// pointers to other types are described as pointers to an opaque type
+/// and all other types fall back to [`TypeInfo::opaque`].
+#[doc(hidden)]
+pub struct TypeInfoProbe<T>(PhantomData<T>);
+
+impl<T> TypeInfoProbe<T> {
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait DescribeKnown {
+    fn type_info(&self) -> TypeInfo;
+}
+
+impl<T: Describable> DescribeKnown for &TypeInfoProbe<T> {
+    fn type_info(&self) -> TypeInfo {
+        T::type_info()
+    }
+}
+
+#[doc(hidden)]
+pub trait DescribePointer {
+    fn type_info(&self) -> TypeInfo;
+}
+
+impl<T, P: PointerPolicy> DescribePointer for &&TypeInfoProbe<Ptr64<T, P>> {
+    fn type_info(&self) -> TypeInfo {
+        TypeInfo::pointer::<Ptr64<T, P>>(TypeInfo::opaque::<T>, P::canonicalize)
+    }
+}
+
+impl<T, B: PointerCompression + Default> DescribePointer
+    for &&TypeInfoProbe<CompressedPtr32<T, B>>
+{
+    fn type_info(&self) -> TypeInfo {
+        TypeInfo::pointer::<CompressedPtr32<T, B>>(
+            TypeInfo::opaque::<T>,
+            resolve_compressed::<T, B>,
+        )
+    }
+}
+
+#[doc(hidden)]
+pub trait DescribeOpaque {
+    fn type_info(&self) -> TypeInfo;
+}
+
+impl<T> DescribeOpaque for TypeInfoProbe<T> {
+    fn type_info(&self) -> TypeInfo {
+        TypeInfo::opaque::<T>()
+    }
+}
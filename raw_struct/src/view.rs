@@ -3,11 +3,35 @@ use core::{
     mem,
 };
 
-use crate::CopyConstructable;
+use crate::{
+    CopyConstructable,
+    Describable,
+    FieldVisitor,
+    MemoryView,
+    Reference,
+    StructValue,
+    TypeInfo,
+};
 
 pub trait Viewable {
     fn name() -> &'static str;
     fn fields() -> &'static [&'static dyn ViewableField];
+
+    /// Visit all fields of the referenced object, including inherited fields.
+    /// See [`FieldVisitor`] for an example.
+    fn visit_fields<M: MemoryView, F: FieldVisitor<M> + ?Sized>(
+        reference: &Reference<Self, M>,
+        visitor: &mut F,
+    ) where
+        Self: Describable,
+    {
+        StructValue::new(
+            reference.memory(),
+            reference.memory_address(),
+            Self::type_info(),
+        )
+        .visit_fields(visitor)
+    }
 }
 
 pub trait ViewableSized: Viewable {
@@ -35,11 +59,24 @@ impl<T> ViewableExtends<T> for T {}
 pub trait ViewableField {
     fn name(&self) -> &'static str;
     fn offset(&self) -> u64;
+
+    /// Type information of the field value.
+    /// Defaults to [`TypeInfo::unknown`].
+    fn type_info(&self) -> TypeInfo {
+        TypeInfo::unknown()
+    }
+
+    /// Pointer chain offsets of chained fields.
+    /// See [`ChainedViewableField`].
+    fn chain(&self) -> &'static [u64] {
+        &[]
+    }
 }
 
 pub struct TypedViewableField<V, T> {
     name: &'static str,
     offset_fn: &'static dyn Fn() -> u64,
+    type_info: fn() -> TypeInfo,
     _type: PhantomData<(V, T)>,
 }
 
@@ -48,9 +85,16 @@ impl<V, T> TypedViewableField<V, T> {
         Self {
             name,
             offset_fn,
+            type_info: TypeInfo::opaque::<T>,
             _type: PhantomData {},
         }
     }
+
+    /// Override the type information, which defaults to [`TypeInfo::opaque`].
+    pub const fn with_type_info(mut self, type_info: fn() -> TypeInfo) -> Self {
+        self.type_info = type_info;
+        self
+    }
 }
 
 impl<V, T> ViewableField for TypedViewableField<V, T> {
@@ -61,4 +105,68 @@ impl<V, T> ViewableField for TypedViewableField<V, T> {
     fn offset(&self) -> u64 {
         (self.offset_fn)()
     }
+
+    fn type_info(&self) -> TypeInfo {
+        (self.type_info)()
+    }
+}
+
+/// A field which is located at the end of a pointer chain.
+/// The pointer at `offset` is followed, and afterwards every pointer at the chain offsets
+/// except for the last one, which is the offset of the field value itself.
+///
+/// See [`PtrChain`](crate::builtins::PtrChain) for more details.
+pub struct ChainedViewableField<V, T> {
+    name: &'static str,
+    offset_fn: &'static dyn Fn() -> u64,
+    chain: &'static [u64],
+    type_info: fn() -> TypeInfo,
+    _type: PhantomData<(V, T)>,
+}
+
+impl<V, T> ChainedViewableField<V, T> {
+    pub const fn define(
+        name: &'static str,
+        offset_fn: &'static dyn Fn() -> u64,
+        chain: &'static [u64],
+    ) -> Self {
+        Self {
+            name,
+            offset_fn,
+            chain,
+            type_info: TypeInfo::opaque::<T>,
+            _type: PhantomData {},
+        }
+    }
+
+    /// Override the type information, which defaults to [`TypeInfo::opaque`].
+    pub const fn with_type_info(mut self, type_info: fn() -> TypeInfo) -> Self {
+        self.type_info = type_info;
+        self
+    }
+
+    /// Offsets applied after following the pointer located at the field offset.
+    pub fn chain(&self) -> &'static [u64] {
+        self.chain
+    }
+}
+
+impl<V, T> ViewableField for ChainedViewableField<V, T> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Offset of the first pointer of the chain.
+    fn offset(&self) -> u64 {
+        (self.offset_fn)()
+    }
+
+    /// Type information of the value at the end of the chain.
+    fn type_info(&self) -> TypeInfo {
+        (self.type_info)()
+    }
+
+    fn chain(&self) -> &'static [u64] {
+        self.chain
+    }
 }
@@ -0,0 +1,274 @@
+use alloc::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    vec,
+    vec::Vec,
+};
+use core::cell::{
+    Cell,
+    RefCell,
+};
+
+use crate::{
+    MemoryView,
+    MemoryViewDereferenceable,
+};
+
+#[derive(Debug, Clone)]
+struct CachedPage {
+    generation: u64,
+
+    /// `None` if the page could not be fetched as a whole.
+    data: Option<Vec<u8>>,
+}
+
+/// Caches reads of the underlying memory view in pages of a fixed size.
+///
+/// The first read touching a page fetches the whole page, every subsequent read
+/// is served from the cache until the page has been invalidated.
+/// Pages which could not be fetched as a whole (e.g. partially unmapped pages)
+/// are remembered as failed for the current generation and the requested range is read directly instead.
+///
+/// Invalidation is generation based: [`CachedMemory::invalidate_all`] only increments the
+/// current generation and outdated pages will be fetched again on their next access.
+/// ```rust
+/// # use raw_struct::{MemoryView, views::CachedMemory};
+/// let memory = [0x11u8; 0x2000];
+/// let memory = CachedMemory::new(memory.as_slice());
+///
+/// let mut buffer = [0u8; 0x04];
+/// memory.read_memory(0x10, &mut buffer).unwrap();
+/// assert_eq!(memory.cached_pages(), 1);
+///
+/// // Once per tick
+/// memory.invalidate_all();
+/// ```
+#[derive(Debug)]
+pub struct CachedMemory<M: MemoryView> {
+    memory: M,
+    page_size: u64,
+
+    generation: Cell<u64>,
+    pages: RefCell<BTreeMap<u64, CachedPage>>,
+}
+
+impl<M: MemoryView> CachedMemory<M> {
+    pub fn new(memory: M) -> Self {
+        Self::with_page_size(memory, 0x1000)
+    }
+
+    /// # Panics
+    /// Panics if `page_size` is not a power of two.
+    pub fn with_page_size(memory: M, page_size: u64) -> Self {
+        assert!(page_size.is_power_of_two());
+
+        Self {
+            memory,
+            page_size,
+
+            generation: Cell::new(0),
+            pages: Default::default(),
+        }
+    }
+
+    pub fn memory(&self) -> &M {
+        &self.memory
+    }
+
+    pub fn into_inner(self) -> M {
+        self.memory
+    }
+
+    pub fn page_size(&self) -> u64 {
+        self.page_size
+    }
+
+    /// The current cache generation.
+    /// Pages fetched within an older generation are considered outdated.
+    pub fn generation(&self) -> u64 {
+        self.generation.get()
+    }
+
+    /// Number of pages cached within the current generation.
+    pub fn cached_pages(&self) -> usize {
+        let generation = self.generation.get();
+        self.pages
+            .borrow()
+            .values()
+            .filter(|page| page.generation == generation && page.data.is_some())
+            .count()
+    }
+
+    /// Mark all cached pages as outdated.
+    pub fn invalidate_all(&self) {
+        self.generation.set(self.generation.get() + 1);
+    }
+
+    /// Remove all pages overlapping the address range `address..address + len` from the cache.
+    pub fn invalidate_range(&self, address: u64, len: u64) {
+        if len == 0 {
+            return;
+        }
+
+        let first_page = address / self.page_size;
+        let last_page = address.saturating_add(len - 1) / self.page_size;
+
+        let mut pages = self.pages.borrow_mut();
+        let outdated = pages
+            .range(first_page..=last_page)
+            .map(|(page, _)| *page)
+            .collect::<Vec<_>>();
+
+        for page in outdated {
+            pages.remove(&page);
+        }
+    }
+
+    /// Remove all pages from the cache and release their memory.
+    pub fn clear(&self) {
+        self.pages.borrow_mut().clear();
+    }
+
+    /// Copy `buffer.len()` bytes at `page_offset` of page `page` into the buffer.
+    /// Returns `false` if the page could not be fetched.
+    fn read_page(&self, page: u64, page_offset: usize, buffer: &mut [u8]) -> bool {
+        let generation = self.generation.get();
+
+        let outdated = {
+            let mut pages = self.pages.borrow_mut();
+            match pages.get(&page) {
+                Some(cached) if cached.generation == generation => {
+                    let Some(data) = &cached.data else {
+                        return false;
+                    };
+
+                    buffer.copy_from_slice(&data[page_offset..page_offset + buffer.len()]);
+                    return true;
+                }
+                _ => pages.remove(&page).and_then(|cached| cached.data),
+            }
+        };
+
+        /* the cache must not be borrowed while reading as the underlying view may access it */
+        let mut data = outdated.unwrap_or_else(|| vec![0u8; self.page_size as usize]);
+        let data = self
+            .memory
+            .read_memory(page * self.page_size, &mut data)
+            .is_ok()
+            .then_some(data);
+
+        if let Some(data) = &data {
+            buffer.copy_from_slice(&data[page_offset..page_offset + buffer.len()]);
+        }
+
+        let fetched = data.is_some();
+        self.pages
+            .borrow_mut()
+            .insert(page, CachedPage { generation, data });
+        fetched
+    }
+
+    /// Fetch all outdated pages touched by `requests` with a single batched read.
+    fn fetch_pages(&self, requests: &[(u64, &mut [u8])]) {
+        let generation = self.generation.get();
+
+        let outdated = {
+            let pages = self.pages.borrow();
+            requests
+                .iter()
+                .filter(|(_, buffer)| !buffer.is_empty())
+                .flat_map(|(offset, buffer)| {
+                    let first_page = offset / self.page_size;
+                    let last_page = offset.saturating_add(buffer.len() as u64 - 1) / self.page_size;
+                    first_page..=last_page
+                })
+                .filter(|page| {
+                    pages
+                        .get(page)
+                        .is_none_or(|cached| cached.generation != generation)
+                })
+                .collect::<BTreeSet<_>>()
+        };
+
+        if outdated.is_empty() {
+            return;
+        }
+
+        let mut data = outdated
+            .iter()
+            .map(|_| vec![0u8; self.page_size as usize])
+            .collect::<Vec<_>>();
+
+        let mut page_requests = outdated
+            .iter()
+            .zip(data.iter_mut())
+            .map(|(page, data)| (page * self.page_size, data.as_mut_slice()))
+            .collect::<Vec<_>>();
+
+        if self.memory.read_memory_batch(&mut page_requests).is_err() {
+            /* the failing pages are determined when fetching them individually */
+            return;
+        }
+
+        let mut pages = self.pages.borrow_mut();
+        for (page, data) in outdated.into_iter().zip(data) {
+            pages.insert(
+                page,
+                CachedPage {
+                    generation,
+                    data: Some(data),
+                },
+            );
+        }
+    }
+}
+
+impl<M: MemoryView> MemoryView for CachedMemory<M> {
+    type AccessError = M::AccessError;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        if offset.checked_add(buffer.len() as u64).is_none() {
+            /* reads reaching the end of the address space bypass the cache, the underlying view reports any access error */
+            return self.memory.read_memory(offset, buffer);
+        }
+
+        let mut address = offset;
+        let mut buffer = buffer;
+        while !buffer.is_empty() {
+            let page = address / self.page_size;
+            let page_offset = (address % self.page_size) as usize;
+            let count = (self.page_size as usize - page_offset).min(buffer.len());
+
+            let (chunk, remaining) = buffer.split_at_mut(count);
+            if !self.read_page(page, page_offset, chunk) {
+                self.memory.read_memory(address, chunk)?;
+            }
+
+            buffer = remaining;
+            address += count as u64;
+        }
+
+        Ok(())
+    }
+
+    fn read_memory_batch(
+        &self,
+        requests: &mut [(u64, &mut [u8])],
+    ) -> Result<(), Self::AccessError> {
+        self.fetch_pages(requests);
+
+        for (offset, buffer) in requests.iter_mut() {
+            self.read_memory(*offset, buffer)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<M: MemoryViewDereferenceable> MemoryViewDereferenceable for CachedMemory<M> {
+    fn dereference(&self, address: u64) -> Result<u64, Self::AccessError> {
+        self.memory.dereference(address)
+    }
+}
@@ -0,0 +1,215 @@
+use std::{
+    fmt,
+    fs::File,
+    io,
+    path::Path,
+};
+
+use crate::MemoryView;
+
+#[derive(Debug)]
+pub enum FileAccessError {
+    /// The requested range does not lie within the file.
+    OutOfRange {
+        address: u64,
+        len: usize,
+    },
+
+    /// The file ended before the requested range could be read completely.
+    ShortRead {
+        address: u64,
+        len: usize,
+        read: usize,
+    },
+
+    Io(io::Error),
+}
+
+impl fmt::Display for FileAccessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfRange { address, len } => write!(
+                f,
+                "memory range 0x{:X} (0x{:X} bytes) is outside of the file",
+                address, len
+            ),
+            Self::ShortRead { address, len, read } => write!(
+                f,
+                "short read at 0x{:X}: expected 0x{:X} bytes but got 0x{:X}",
+                address, len, read
+            ),
+            Self::Io(inner) => inner.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for FileAccessError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+/// Translate a memory address into a file offset and validate that the
+/// requested range is covered by a file of `file_len` bytes.
+fn file_range(
+    base_address: u64,
+    file_len: u64,
+    address: u64,
+    len: usize,
+) -> Result<u64, FileAccessError> {
+    let out_of_range = || FileAccessError::OutOfRange { address, len };
+
+    let offset = address.checked_sub(base_address).ok_or_else(out_of_range)?;
+    let end = offset.checked_add(len as u64).ok_or_else(out_of_range)?;
+    if end > file_len {
+        return Err(out_of_range());
+    }
+
+    Ok(offset)
+}
+
+#[cfg(unix)]
+fn read_at(file: &File, buffer: &mut [u8], offset: u64) -> io::Result<usize> {
+    std::os::unix::fs::FileExt::read_at(file, buffer, offset)
+}
+
+#[cfg(windows)]
+fn read_at(file: &File, buffer: &mut [u8], offset: u64) -> io::Result<usize> {
+    std::os::windows::fs::FileExt::seek_read(file, buffer, offset)
+}
+
+/// A memory view backed by a file (e.g. a raw memory dump) using positional reads.
+///
+/// The file is never loaded into memory. Every read is served by a single
+/// positional read call (`pread` on unix), hence the view can be shared across threads.
+/// The first byte of the file is mapped to `base_address`.
+#[derive(Debug)]
+pub struct FileMemory {
+    file: File,
+    file_len: u64,
+    base_address: u64,
+}
+
+impl FileMemory {
+    pub fn new(file: File) -> io::Result<Self> {
+        Self::with_base_address(file, 0x00)
+    }
+
+    pub fn with_base_address(file: File, base_address: u64) -> io::Result<Self> {
+        let file_len = file.metadata()?.len();
+        Ok(Self {
+            file,
+            file_len,
+            base_address,
+        })
+    }
+
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(File::open(path)?)
+    }
+
+    pub fn file(&self) -> &File {
+        &self.file
+    }
+
+    pub fn base_address(&self) -> u64 {
+        self.base_address
+    }
+
+    /// Length of the file at the time the view has been created.
+    pub fn len(&self) -> u64 {
+        self.file_len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.file_len == 0
+    }
+}
+
+impl MemoryView for FileMemory {
+    type AccessError = FileAccessError;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        let address = offset;
+        let mut file_offset = file_range(self.base_address, self.file_len, address, buffer.len())?;
+
+        let mut read = 0;
+        while read < buffer.len() {
+            match read_at(&self.file, &mut buffer[read..], file_offset) {
+                Ok(0) => {
+                    return Err(FileAccessError::ShortRead {
+                        address,
+                        len: buffer.len(),
+                        read,
+                    })
+                }
+                Ok(count) => {
+                    read += count;
+                    file_offset += count as u64;
+                }
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(FileAccessError::Io(err)),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A memory view backed by a memory mapped file.
+///
+/// # Note
+/// Modifying the underlying file while it is mapped is undefined behaviour.
+/// Prefer [`FileMemory`] when the file might change.
+#[cfg(feature = "mmap")]
+#[derive(Debug)]
+pub struct MappedFileMemory {
+    mapping: memmap2::Mmap,
+    base_address: u64,
+}
+
+#[cfg(feature = "mmap")]
+impl MappedFileMemory {
+    /// # Safety
+    /// The file must not be modified or truncated while the mapping exists.
+    pub unsafe fn new(file: &File) -> io::Result<Self> {
+        Self::with_base_address(file, 0x00)
+    }
+
+    /// # Safety
+    /// The file must not be modified or truncated while the mapping exists.
+    pub unsafe fn with_base_address(file: &File, base_address: u64) -> io::Result<Self> {
+        Ok(Self {
+            mapping: memmap2::Mmap::map(file)?,
+            base_address,
+        })
+    }
+
+    pub fn base_address(&self) -> u64 {
+        self.base_address
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.mapping
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl MemoryView for MappedFileMemory {
+    type AccessError = FileAccessError;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        let file_offset = file_range(
+            self.base_address,
+            self.mapping.len() as u64,
+            offset,
+            buffer.len(),
+        )? as usize;
+
+        buffer.copy_from_slice(&self.mapping[file_offset..file_offset + buffer.len()]);
+        Ok(())
+    }
+}
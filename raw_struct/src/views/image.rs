@@ -0,0 +1,307 @@
+use alloc::{
+    vec,
+    vec::Vec,
+};
+use core::{
+    fmt,
+    marker::{
+        self,
+        PhantomData,
+    },
+    mem,
+    slice,
+};
+
+use crate::{
+    builtins::Ptr64,
+    memory::value_bytes,
+    views::{
+        RegionOverlap,
+        SparseMemory,
+    },
+    MemoryView,
+    PaddingFree,
+    Reference,
+    TypedViewableField,
+    ViewableExtends,
+    ViewableField,
+    ViewableSized,
+};
+
+/// An object could not be allocated by the [`MemoryImageBuilder`].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum AllocationError {
+    /// The object overlaps with an already allocated object.
+    RegionOverlap(RegionOverlap),
+
+    /// The object does not fit into the remaining address space.
+    AddressSpaceExhausted { address: u64, len: usize },
+}
+
+impl From<RegionOverlap> for AllocationError {
+    fn from(value: RegionOverlap) -> Self {
+        Self::RegionOverlap(value)
+    }
+}
+
+impl fmt::Display for AllocationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RegionOverlap(overlap) => overlap.fmt(f),
+            Self::AddressSpaceExhausted { address, len } => write!(
+                f,
+                "region 0x{:X} (0x{:X} bytes) exceeds the address space",
+                address, len
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AllocationError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for AllocationError {}
+
+/// An object which has been allocated by the [`MemoryImageBuilder`].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Allocation<T: ?Sized> {
+    address: u64,
+    len: usize,
+    _type: PhantomData<T>,
+}
+
+impl<T: ?Sized> Clone for Allocation<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> marker::Copy for Allocation<T> {}
+
+impl<T: ?Sized> Allocation<T> {
+    fn new(address: u64, len: usize) -> Self {
+        Self {
+            address,
+            len,
+            _type: PhantomData {},
+        }
+    }
+
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    /// Size of the allocation in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn as_ptr(&self) -> Ptr64<T> {
+        Ptr64::from_address(self.address)
+    }
+
+    pub fn reference<M: MemoryView>(&self, memory: M) -> Reference<T, M> {
+        Reference::new(memory, self.address)
+    }
+}
+
+/// Builder for memory images containing objects of `#[raw_struct]` types.
+/// Intended to create fixtures for tests.
+///
+/// ```rust
+/// # use raw_struct::{raw_struct, builtins::Ptr64, views::MemoryImageBuilder};
+/// #[raw_struct(size = 0x10)]
+/// struct Node {
+///     #[field(offset = 0x00)]
+///     pub value: u32,
+///
+///     #[field(offset = 0x08)]
+///     pub next: Ptr64<Node>,
+/// }
+///
+/// let mut builder = MemoryImageBuilder::new();
+/// let node_a = builder.allocate::<Node>()?;
+/// let node_b = builder.allocate::<Node>()?;
+/// builder.set_field(&node_a, Node::value, 0x11);
+/// builder.set_pointer(&node_a, Node::next, &node_b);
+/// builder.set_field(&node_b, Node::value, 0x22);
+///
+/// let memory = builder.build();
+/// let node = node_a.reference(&memory);
+/// let node = node.dereference_field(Node::next).unwrap();
+/// assert_eq!(node.read_field(Node::value), Ok(0x22));
+/// # Ok::<(), raw_struct::views::AllocationError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct MemoryImageBuilder {
+    memory: SparseMemory,
+    next_address: u64,
+    alignment: u64,
+}
+
+impl Default for MemoryImageBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryImageBuilder {
+    pub fn new() -> Self {
+        Self::with_base_address(0x10000)
+    }
+
+    /// Create a new builder which automatically assigns addresses starting at `base_address`.
+    pub fn with_base_address(base_address: u64) -> Self {
+        Self {
+            memory: SparseMemory::new(),
+            next_address: base_address,
+            alignment: 0x10,
+        }
+    }
+
+    /// Set the alignment of automatically assigned addresses.
+    ///
+    /// # Panics
+    /// Panics if `alignment` is not a power of two.
+    pub fn with_alignment(mut self, alignment: u64) -> Self {
+        assert!(alignment.is_power_of_two());
+        self.alignment = alignment;
+        self
+    }
+
+    /// Allocate a zero initialized object at the next free address.
+    pub fn allocate<T: ViewableSized>(&mut self) -> Result<Allocation<T>, AllocationError> {
+        let len = T::memory_size();
+        let address = self.allocate_region(vec![0u8; len])?;
+        Ok(Allocation::new(address, len))
+    }
+
+    /// Allocate a zero initialized object at `address`.
+    pub fn allocate_at<T: ViewableSized>(
+        &mut self,
+        address: u64,
+    ) -> Result<Allocation<T>, AllocationError> {
+        let len = T::memory_size();
+        self.insert_region(address, vec![0u8; len])?;
+        Ok(Allocation::new(address, len))
+    }
+
+    /// Allocate an array containing `values` at the next free address.
+    pub fn allocate_array<T: PaddingFree>(
+        &mut self,
+        values: &[T],
+    ) -> Result<Allocation<[T]>, AllocationError> {
+        let data = value_bytes(values).to_vec();
+        let len = data.len();
+        let address = self.allocate_region(data)?;
+        Ok(Allocation::new(address, len))
+    }
+
+    /// Allocate an array containing `values` at `address`.
+    pub fn allocate_array_at<T: PaddingFree>(
+        &mut self,
+        address: u64,
+        values: &[T],
+    ) -> Result<Allocation<[T]>, AllocationError> {
+        let data = value_bytes(values).to_vec();
+        let len = data.len();
+        self.insert_region(address, data)?;
+        Ok(Allocation::new(address, len))
+    }
+
+    /// Set the value of a field of an allocated object.
+    ///
+    /// # Panics
+    /// Panics if the field exceeds the allocated object.
+    pub fn set_field<T, C, R: PaddingFree>(
+        &mut self,
+        object: &Allocation<T>,
+        field: &TypedViewableField<C, R>,
+        value: R,
+    ) where
+        T: ViewableExtends<C>,
+    {
+        let field_offset = field.offset();
+        assert!(
+            field_offset + mem::size_of::<R>() as u64 <= object.len() as u64,
+            "field {} exceeds the allocated object",
+            field.name()
+        );
+
+        self.memory
+            .write(
+                object.address() + field_offset,
+                value_bytes(slice::from_ref(&value)),
+            )
+            .expect("allocated object to be mapped");
+    }
+
+    /// Let a pointer field of an allocated object point to `target`.
+    ///
+    /// # Panics
+    /// Panics if the field exceeds the allocated object.
+    pub fn set_pointer<T, C, R: ?Sized, P>(
+        &mut self,
+        object: &Allocation<T>,
+        field: &TypedViewableField<C, Ptr64<R, P>>,
+        target: &Allocation<R>,
+    ) where
+        T: ViewableExtends<C>,
+    {
+        self.set_field(object, field, Ptr64::from_address(target.address()));
+    }
+
+    /// The memory image build so far.
+    pub fn memory(&self) -> &SparseMemory {
+        &self.memory
+    }
+
+    pub fn build(self) -> SparseMemory {
+        self.memory
+    }
+
+    fn allocate_region(&mut self, data: Vec<u8>) -> Result<u64, AllocationError> {
+        let exhausted = AllocationError::AddressSpaceExhausted {
+            address: self.next_address,
+            len: data.len(),
+        };
+
+        let mut address = self.next_address;
+        let end = loop {
+            address = address
+                .checked_next_multiple_of(self.alignment)
+                .ok_or(exhausted)?;
+
+            match self.insert_region(address, data.as_slice()) {
+                Ok(end) => break end,
+                Err(AllocationError::RegionOverlap(overlap)) => {
+                    address = overlap.region_address + overlap.region_len as u64;
+                }
+                Err(AllocationError::AddressSpaceExhausted { .. }) => return Err(exhausted),
+            }
+        };
+
+        self.next_address = end;
+        Ok(address)
+    }
+
+    fn insert_region(
+        &mut self,
+        address: u64,
+        data: impl Into<Vec<u8>>,
+    ) -> Result<u64, AllocationError> {
+        let data = data.into();
+        let len = data.len();
+        let end = address
+            .checked_add(len as u64)
+            .ok_or(AllocationError::AddressSpaceExhausted { address, len })?;
+
+        self.memory.insert(address, data)?;
+        Ok(end)
+    }
+}
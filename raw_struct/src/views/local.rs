@@ -0,0 +1,182 @@
+use core::{
+    fmt,
+    ptr,
+};
+
+use crate::{
+    MemoryView,
+    MemoryViewDereferenceable,
+};
+
+#[derive(Debug)]
+pub enum LocalAccessError {
+    /// The address range is not (completely) mapped or not readable.
+    InvalidAddress { address: u64, len: usize },
+
+    /// The memory maps of the current process could not be read.
+    #[cfg(all(feature = "std", target_os = "linux"))]
+    Io(std::io::Error),
+}
+
+impl fmt::Display for LocalAccessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidAddress { address, len } => write!(
+                f,
+                "memory at 0x{:X} (0x{:X} bytes) is not readable",
+                address, len
+            ),
+            #[cfg(all(feature = "std", target_os = "linux"))]
+            Self::Io(inner) => write!(f, "failed to read process memory maps: {}", inner),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LocalAccessError {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for LocalAccessError {}
+
+/// A memory view of the current process.
+/// Reads are plain memory copies from the given address.
+///
+/// Pointers are resolved as is, hence [`MemoryViewDereferenceable::dereference`] is the identity.
+#[derive(Debug, Clone, Copy)]
+pub struct LocalMemory {
+    probing: bool,
+}
+
+impl LocalMemory {
+    /// # Safety
+    /// Every address range read through this view must be valid for reads.
+    /// Reading invalid memory is undefined behaviour and will most likely crash the process.
+    pub unsafe fn new() -> Self {
+        Self { probing: false }
+    }
+
+    /// Validate every read against `/proc/self/maps` before accessing the memory.
+    /// Reads of unmapped or unreadable memory will return an error instead of crashing.
+    /// Note that this requires parsing the memory maps for every read.
+    ///
+    /// # Safety
+    /// Memory must not be unmapped concurrently while being read.
+    #[cfg(all(feature = "std", target_os = "linux"))]
+    pub unsafe fn with_probing() -> Self {
+        Self { probing: true }
+    }
+
+    pub fn is_probing(&self) -> bool {
+        self.probing
+    }
+
+    #[cfg(all(feature = "std", target_os = "linux"))]
+    fn probe(&self, address: usize, len: usize) -> Result<bool, LocalAccessError> {
+        let maps = std::fs::read_to_string("/proc/self/maps").map_err(LocalAccessError::Io)?;
+        Ok(maps_contain(&maps, address, len))
+    }
+
+    #[cfg(not(all(feature = "std", target_os = "linux")))]
+    fn probe(&self, _address: usize, _len: usize) -> Result<bool, LocalAccessError> {
+        Ok(true)
+    }
+}
+
+/// Check if the address range is covered by readable memory maps.
+/// `maps` must be formatted like `/proc/<pid>/maps`.
+#[cfg(all(feature = "std", target_os = "linux"))]
+fn maps_contain(maps: &str, address: usize, len: usize) -> bool {
+    let end = address + len;
+    let mut cursor = address;
+
+    for line in maps.lines() {
+        let mut columns = line.split_whitespace();
+        let (Some(range), Some(permissions)) = (columns.next(), columns.next()) else {
+            continue;
+        };
+
+        let Some((map_start, map_end)) = range.split_once('-') else {
+            continue;
+        };
+
+        let (Ok(map_start), Ok(map_end)) = (
+            usize::from_str_radix(map_start, 16),
+            usize::from_str_radix(map_end, 16),
+        ) else {
+            continue;
+        };
+
+        if map_end <= cursor {
+            continue;
+        }
+
+        if map_start > cursor || !permissions.starts_with('r') {
+            return false;
+        }
+
+        cursor = map_end;
+        if cursor >= end {
+            return true;
+        }
+    }
+
+    false
+}
+
+impl MemoryView for LocalMemory {
+    type AccessError = LocalAccessError;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        if buffer.is_empty() {
+            return Ok(());
+        }
+
+        let invalid_address = LocalAccessError::InvalidAddress {
+            address: offset,
+            len: buffer.len(),
+        };
+
+        let address = match usize::try_from(offset) {
+            Ok(address) if address != 0 && address.checked_add(buffer.len()).is_some() => address,
+            _ => return Err(invalid_address),
+        };
+
+        if self.probing && !self.probe(address, buffer.len())? {
+            return Err(invalid_address);
+        }
+
+        unsafe {
+            ptr::copy_nonoverlapping(address as *const u8, buffer.as_mut_ptr(), buffer.len());
+        }
+
+        Ok(())
+    }
+}
+
+impl MemoryViewDereferenceable for LocalMemory {
+    fn dereference(&self, address: u64) -> Result<u64, Self::AccessError> {
+        Ok(address)
+    }
+}
+
+#[cfg(all(test, feature = "std", target_os = "linux"))]
+mod test {
+    use super::maps_contain;
+
+    #[test]
+    fn test_maps_contain() {
+        let maps = "\
+00400000-00452000 r-xp 00000000 08:02 173521      /usr/bin/dbus-daemon
+00651000-00652000 r--p 00051000 08:02 173521      /usr/bin/dbus-daemon
+00652000-00655000 rw-p 00052000 08:02 173521      /usr/bin/dbus-daemon
+00e03000-00e24000 rw-p 00000000 00:00 0           [heap]
+7fff0000-7fff1000 ---p 00000000 00:00 0
+";
+
+        assert!(maps_contain(maps, 0x00400000, 0x10));
+        assert!(maps_contain(maps, 0x00651FF0, 0x20));
+        assert!(!maps_contain(maps, 0x00654FF0, 0x20));
+        assert!(!maps_contain(maps, 0x00300000, 0x10));
+        assert!(!maps_contain(maps, 0x7fff0000, 0x10));
+    }
+}
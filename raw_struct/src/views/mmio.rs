@@ -0,0 +1,154 @@
+use core::{
+    borrow::Borrow,
+    fmt,
+    ptr,
+    slice,
+};
+
+use crate::{
+    memory::value_bytes,
+    MemoryView,
+    PaddingFree,
+    Reference,
+    TypedViewableField,
+    Viewable,
+    ViewableExtends,
+    ViewableField,
+};
+
+/// The access can not be performed as a single volatile access.
+/// Only naturally aligned accesses of 1, 2, 4 or 8 bytes are supported.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct UnsupportedMmioAccess {
+    pub address: u64,
+    pub len: usize,
+}
+
+impl fmt::Display for UnsupportedMmioAccess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unsupported mmio access of 0x{:X} bytes at 0x{:X}",
+            self.len, self.address
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnsupportedMmioAccess {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for UnsupportedMmioAccess {}
+
+/// A memory view of memory mapped registers.
+///
+/// Every read and write is performed as a single volatile access with the natural width
+/// of the accessed value. Hence `#[raw_struct]` layouts can be used as register block definitions:
+/// ```rust,no_run
+/// # use raw_struct::{raw_struct, views::MmioMemory, Reference};
+/// #[raw_struct(size = 0x08)]
+/// struct Uart {
+///     #[field(offset = 0x00)]
+///     pub data: u32,
+///
+///     #[field(offset = 0x04)]
+///     pub status: u32,
+/// }
+///
+/// let uart = Reference::<Uart, _>::new(unsafe { MmioMemory::new() }, 0x4000_C000);
+/// if uart.read_field(Uart::status).unwrap() & 0x01 > 0 {
+///     uart.write_field(Uart::data, b'A' as u32).unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct MmioMemory {
+    _private: (),
+}
+
+impl MmioMemory {
+    /// # Safety
+    /// Every address accessed through this view must be valid for volatile reads and writes.
+    pub unsafe fn new() -> Self {
+        Self { _private: () }
+    }
+
+    fn access_address(offset: u64, len: usize) -> Result<usize, UnsupportedMmioAccess> {
+        let unsupported = UnsupportedMmioAccess {
+            address: offset,
+            len,
+        };
+
+        if !matches!(len, 1 | 2 | 4 | 8) || !offset.is_multiple_of(len as u64) {
+            return Err(unsupported);
+        }
+
+        usize::try_from(offset).map_err(|_| unsupported)
+    }
+
+    /// Write `data` with a single volatile write.
+    pub fn write_memory(&self, offset: u64, data: &[u8]) -> Result<(), UnsupportedMmioAccess> {
+        let address = Self::access_address(offset, data.len())?;
+        unsafe {
+            match data.len() {
+                1 => ptr::write_volatile(address as *mut u8, data[0]),
+                2 => ptr::write_volatile(
+                    address as *mut u16,
+                    u16::from_ne_bytes(data.try_into().unwrap()),
+                ),
+                4 => ptr::write_volatile(
+                    address as *mut u32,
+                    u32::from_ne_bytes(data.try_into().unwrap()),
+                ),
+                8 => ptr::write_volatile(
+                    address as *mut u64,
+                    u64::from_ne_bytes(data.try_into().unwrap()),
+                ),
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl MemoryView for MmioMemory {
+    type AccessError = UnsupportedMmioAccess;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        let address = Self::access_address(offset, buffer.len())?;
+        unsafe {
+            match buffer.len() {
+                1 => buffer[0] = ptr::read_volatile(address as *const u8),
+                2 => {
+                    buffer.copy_from_slice(&ptr::read_volatile(address as *const u16).to_ne_bytes())
+                }
+                4 => {
+                    buffer.copy_from_slice(&ptr::read_volatile(address as *const u32).to_ne_bytes())
+                }
+                8 => {
+                    buffer.copy_from_slice(&ptr::read_volatile(address as *const u64).to_ne_bytes())
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Viewable, M: Borrow<MmioMemory>> Reference<T, M> {
+    /// Write a register with a single volatile write.
+    pub fn write_field<R: PaddingFree, C>(
+        &self,
+        field: &TypedViewableField<C, R>,
+        value: R,
+    ) -> Result<(), UnsupportedMmioAccess>
+    where
+        T: ViewableExtends<C>,
+    {
+        self.memory().borrow().write_memory(
+            self.memory_address() + field.offset(),
+            value_bytes(slice::from_ref(&value)),
+        )
+    }
+}
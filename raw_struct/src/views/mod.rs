@@ -0,0 +1,59 @@
+mod local;
+pub use local::{
+    LocalAccessError,
+    LocalMemory,
+};
+
+mod mmio;
+pub use mmio::{
+    MmioMemory,
+    UnsupportedMmioAccess,
+};
+
+mod page_table;
+pub use page_table::{
+    PageTableMemory,
+    PagingMode,
+    TranslationError,
+};
+
+mod window;
+pub use window::{
+    RebasedAccessError,
+    RebasedMemory,
+    WindowAccessError,
+    WindowMemory,
+};
+
+#[cfg(all(feature = "std", any(unix, windows)))]
+mod file;
+#[cfg(all(feature = "mmap", any(unix, windows)))]
+pub use file::MappedFileMemory;
+#[cfg(all(feature = "std", any(unix, windows)))]
+pub use file::{
+    FileAccessError,
+    FileMemory,
+};
+
+#[cfg(feature = "alloc")]
+mod sparse;
+#[cfg(feature = "alloc")]
+pub use sparse::{
+    RegionOverlap,
+    SparseMemory,
+    UnmappedMemory,
+};
+
+#[cfg(feature = "alloc")]
+mod image;
+#[cfg(feature = "alloc")]
+pub use image::{
+    Allocation,
+    AllocationError,
+    MemoryImageBuilder,
+};
+
+#[cfg(feature = "alloc")]
+mod cached;
+#[cfg(feature = "alloc")]
+pub use cached::CachedMemory;
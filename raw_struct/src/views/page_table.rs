@@ -0,0 +1,258 @@
+use core::{
+    cell::{
+        Cell,
+        RefCell,
+    },
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+};
+
+use crate::{
+    MemoryView,
+    MemoryViewDereferenceable,
+};
+
+const ENTRY_PRESENT: u64 = 1 << 0;
+const ENTRY_PAGE_SIZE: u64 = 1 << 7;
+const ENTRY_ADDRESS_MASK: u64 = 0x000F_FFFF_FFFF_F000;
+
+const PAGE_SIZE: u64 = 0x1000;
+const TLB_SIZE: usize = 16;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum PagingMode {
+    /// 4-level paging (PML4, 48 bit virtual addresses)
+    Level4,
+
+    /// 5-level paging (PML5, 57 bit virtual addresses)
+    Level5,
+}
+
+impl PagingMode {
+    fn levels(&self) -> u32 {
+        match self {
+            Self::Level4 => 4,
+            Self::Level5 => 5,
+        }
+    }
+
+    /// Number of significant virtual address bits.
+    fn address_bits(&self) -> u32 {
+        12 + 9 * self.levels()
+    }
+
+    /// Returns true if all bits above the significant address bits are copies of the most significant one.
+    fn is_canonical(&self, address: u64) -> bool {
+        let unused_bits = 64 - self.address_bits();
+        ((address as i64) << unused_bits >> unused_bits) as u64 == address
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum TranslationError<E> {
+    /// The paging structure entry of `level` (1 = PT, 2 = PD, 3 = PDPT, 4 = PML4, 5 = PML5)
+    /// required to translate `address` is not present.
+    NotPresent {
+        address: u64,
+        level: u32,
+    },
+    /// `address` is not a canonical virtual address of the paging mode.
+    NonCanonical {
+        address: u64,
+    },
+    /// The access of `len` bytes at `address` exceeds the address space.
+    AddressOverflow {
+        address: u64,
+        len: usize,
+    },
+    MemoryAccess(E),
+}
+
+impl<E: Display> fmt::Display for TranslationError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotPresent { address, level } => write!(
+                f,
+                "failed to translate 0x{:X}: level {} entry is not present",
+                address, level
+            ),
+            Self::NonCanonical { address } => write!(
+                f,
+                "failed to translate 0x{:X}: address is not canonical",
+                address
+            ),
+            Self::AddressOverflow { address, len } => write!(
+                f,
+                "access of 0x{:X} bytes at 0x{:X} exceeds the address space",
+                len, address
+            ),
+            Self::MemoryAccess(inner) => inner.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Display + Debug> std::error::Error for TranslationError<E> {}
+
+#[cfg(not(feature = "std"))]
+impl<E: Display + Debug> core::error::Error for TranslationError<E> {}
+
+#[derive(Debug, Clone, Copy)]
+struct TlbEntry {
+    virtual_base: u64,
+    physical_base: u64,
+    page_mask: u64,
+}
+
+/// Virtual memory view on top of a physical memory view (e.g. a physical memory dump)
+/// by walking the x86-64 paging structures starting at the given directory table base (CR3).
+///
+/// Large pages (2 MiB and 1 GiB) are supported.
+/// Recent translations are kept in a small TLB which must be flushed
+/// via [`PageTableMemory::flush_tlb`] if the paging structures change.
+///
+/// Pointers are virtual addresses, hence [`MemoryViewDereferenceable::dereference`] is the identity.
+#[derive(Debug)]
+pub struct PageTableMemory<M: MemoryView> {
+    memory: M,
+    directory_table_base: u64,
+    mode: PagingMode,
+
+    tlb: RefCell<[Option<TlbEntry>; TLB_SIZE]>,
+    tlb_next: Cell<usize>,
+}
+
+impl<M: MemoryView> PageTableMemory<M> {
+    /// Create a view using 4-level paging.
+    pub fn new(memory: M, directory_table_base: u64) -> Self {
+        Self::with_mode(memory, directory_table_base, PagingMode::Level4)
+    }
+
+    pub fn with_mode(memory: M, directory_table_base: u64, mode: PagingMode) -> Self {
+        Self {
+            memory,
+            directory_table_base,
+            mode,
+
+            tlb: RefCell::new([None; TLB_SIZE]),
+            tlb_next: Cell::new(0),
+        }
+    }
+
+    /// The underlying physical memory view.
+    pub fn memory(&self) -> &M {
+        &self.memory
+    }
+
+    pub fn directory_table_base(&self) -> u64 {
+        self.directory_table_base
+    }
+
+    pub fn mode(&self) -> PagingMode {
+        self.mode
+    }
+
+    pub fn flush_tlb(&self) {
+        *self.tlb.borrow_mut() = [None; TLB_SIZE];
+    }
+
+    /// Translate a virtual address into a physical address.
+    pub fn translate(&self, address: u64) -> Result<u64, TranslationError<M::AccessError>> {
+        if !self.mode.is_canonical(address) {
+            return Err(TranslationError::NonCanonical { address });
+        }
+
+        if let Some(entry) = self
+            .tlb
+            .borrow()
+            .iter()
+            .flatten()
+            .find(|entry| address & !entry.page_mask == entry.virtual_base)
+        {
+            return Ok(entry.physical_base | (address & entry.page_mask));
+        }
+
+        let entry = self.walk(address)?;
+
+        let index = self.tlb_next.get();
+        self.tlb.borrow_mut()[index] = Some(entry);
+        self.tlb_next.set((index + 1) % TLB_SIZE);
+
+        Ok(entry.physical_base | (address & entry.page_mask))
+    }
+
+    fn walk(&self, address: u64) -> Result<TlbEntry, TranslationError<M::AccessError>> {
+        let mut table = self.directory_table_base & ENTRY_ADDRESS_MASK;
+        for level in (1..=self.mode.levels()).rev() {
+            let shift = 12 + 9 * (level - 1);
+            let index = (address >> shift) & 0x1FF;
+
+            let mut entry = [0u8; 8];
+            self.memory
+                .read_memory(table + index * 8, &mut entry)
+                .map_err(TranslationError::MemoryAccess)?;
+
+            let entry = u64::from_le_bytes(entry);
+            if entry & ENTRY_PRESENT == 0 {
+                return Err(TranslationError::NotPresent { address, level });
+            }
+
+            /* PT entries are always pages, PDPT and PD entries might map a large page */
+            if level == 1 || (level <= 3 && entry & ENTRY_PAGE_SIZE != 0) {
+                let page_mask = (1u64 << shift) - 1;
+                return Ok(TlbEntry {
+                    virtual_base: address & !page_mask,
+                    physical_base: entry & ENTRY_ADDRESS_MASK & !page_mask,
+                    page_mask,
+                });
+            }
+
+            table = entry & ENTRY_ADDRESS_MASK;
+        }
+
+        unreachable!()
+    }
+}
+
+impl<M: MemoryView> MemoryView for PageTableMemory<M> {
+    type AccessError = TranslationError<M::AccessError>;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        let len = buffer.len();
+        let mut address = offset;
+        let mut buffer = buffer;
+        while !buffer.is_empty() {
+            let count = ((PAGE_SIZE - address % PAGE_SIZE) as usize).min(buffer.len());
+            let (chunk, remaining) = buffer.split_at_mut(count);
+
+            let physical_address = self.translate(address)?;
+            self.memory
+                .read_memory(physical_address, chunk)
+                .map_err(TranslationError::MemoryAccess)?;
+
+            buffer = remaining;
+            if buffer.is_empty() {
+                break;
+            }
+
+            address =
+                address
+                    .checked_add(count as u64)
+                    .ok_or(TranslationError::AddressOverflow {
+                        address: offset,
+                        len,
+                    })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<M: MemoryView> MemoryViewDereferenceable for PageTableMemory<M> {
+    fn dereference(&self, address: u64) -> Result<u64, Self::AccessError> {
+        Ok(address)
+    }
+}
@@ -0,0 +1,281 @@
+use alloc::{
+    collections::BTreeMap,
+    vec,
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::{
+    MemoryView,
+    MemoryViewDereferenceable,
+};
+
+/// A read touched an address which is not backed by any region.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct UnmappedMemory {
+    pub access_address: u64,
+    pub access_len: usize,
+
+    /// The first address of the access which is not mapped,
+    /// or `u64::MAX` if the access exceeds the end of the address space.
+    pub unmapped_address: u64,
+}
+
+impl fmt::Display for UnmappedMemory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "memory at 0x{:X} is not mapped (access of 0x{:X} bytes at 0x{:X})",
+            self.unmapped_address, self.access_len, self.access_address
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnmappedMemory {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for UnmappedMemory {}
+
+/// A region could not be inserted as it overlaps with an existing region.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct RegionOverlap {
+    pub address: u64,
+    pub len: usize,
+
+    pub region_address: u64,
+    pub region_len: usize,
+}
+
+impl fmt::Display for RegionOverlap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "region 0x{:X} (0x{:X} bytes) overlaps with existing region 0x{:X} (0x{:X} bytes)",
+            self.address, self.len, self.region_address, self.region_len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RegionOverlap {}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for RegionOverlap {}
+
+/// A sparse address space consisting of independent byte regions.
+///
+/// In contrast to a plain `&[u8]` this allows placing objects at arbitrary (realistic) addresses.
+/// Pointers are resolved as is, hence [`MemoryViewDereferenceable::dereference`] is the identity.
+/// ```rust
+/// # use raw_struct::views::SparseMemory;
+/// # use raw_struct::MemoryView;
+/// let mut memory = SparseMemory::new();
+/// memory.insert(0x7FF0_0000, 0xDEADBEEFu32.to_le_bytes()).unwrap();
+///
+/// let mut buffer = [0u8; 4];
+/// memory.read_memory(0x7FF0_0000, &mut buffer).unwrap();
+/// assert_eq!(u32::from_le_bytes(buffer), 0xDEADBEEF);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SparseMemory {
+    regions: BTreeMap<u64, Vec<u8>>,
+}
+
+impl SparseMemory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a new region at `address`.
+    /// Fails if the region overlaps with any existing region.
+    ///
+    /// # Panics
+    /// Panics if the region exceeds the 64 bit address space.
+    pub fn insert(&mut self, address: u64, data: impl Into<Vec<u8>>) -> Result<(), RegionOverlap> {
+        let data = data.into();
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let end = region_end(address, data.len());
+        if let Some((region_address, region)) = self
+            .regions
+            .range(..end)
+            .next_back()
+            .filter(|(region_address, region)| region_end(**region_address, region.len()) > address)
+        {
+            return Err(RegionOverlap {
+                address,
+                len: data.len(),
+
+                region_address: *region_address,
+                region_len: region.len(),
+            });
+        }
+
+        self.regions.insert(address, data);
+        Ok(())
+    }
+
+    /// Write `data` to `address` regardless of the existing regions.
+    /// Regions overlapping or adjacent to the written range will be merged into one region.
+    ///
+    /// # Panics
+    /// Panics if the region exceeds the 64 bit address space.
+    pub fn overwrite(&mut self, address: u64, data: &[u8]) {
+        if data.is_empty() {
+            return;
+        }
+
+        let end = region_end(address, data.len());
+        let touching = self
+            .regions
+            .range(..=end)
+            .rev()
+            .take_while(|(region_address, region)| {
+                region_end(**region_address, region.len()) >= address
+            })
+            .map(|(region_address, _)| *region_address)
+            .collect::<Vec<_>>();
+
+        let mut merged_address = address;
+        let mut merged_end = end;
+        let touching = touching
+            .into_iter()
+            .filter_map(|region_address| self.regions.remove_entry(&region_address))
+            .inspect(|(region_address, region)| {
+                merged_address = merged_address.min(*region_address);
+                merged_end = merged_end.max(region_end(*region_address, region.len()));
+            })
+            .collect::<Vec<_>>();
+
+        let mut merged = vec![0u8; (merged_end - merged_address) as usize];
+        for (region_address, region) in touching {
+            let offset = (region_address - merged_address) as usize;
+            merged[offset..offset + region.len()].copy_from_slice(&region);
+        }
+
+        let offset = (address - merged_address) as usize;
+        merged[offset..offset + data.len()].copy_from_slice(data);
+        self.regions.insert(merged_address, merged);
+    }
+
+    /// Write `data` into already mapped memory at `address`.
+    /// Fails without modifying any region if the target range is not fully mapped.
+    pub fn write(&mut self, address: u64, data: &[u8]) -> Result<(), UnmappedMemory> {
+        let unmapped = |unmapped_address| UnmappedMemory {
+            access_address: address,
+            access_len: data.len(),
+
+            unmapped_address,
+        };
+
+        let mut cursor = address;
+        let end = address
+            .checked_add(data.len() as u64)
+            .ok_or(unmapped(address))?;
+        while cursor < end {
+            let (region_address, region) = self.region_at(cursor).ok_or(unmapped(cursor))?;
+            cursor = region_end(region_address, region.len());
+        }
+
+        let mut cursor = address;
+        let mut data = data;
+        while !data.is_empty() {
+            let (region_address, region) = self
+                .regions
+                .range_mut(..=cursor)
+                .next_back()
+                .expect("range to be mapped");
+
+            let region_offset = (cursor - *region_address) as usize;
+            let count = (region.len() - region_offset).min(data.len());
+            region[region_offset..region_offset + count].copy_from_slice(&data[..count]);
+
+            data = &data[count..];
+            cursor += count as u64;
+        }
+
+        Ok(())
+    }
+
+    /// Remove the region starting at `address`.
+    pub fn remove(&mut self, address: u64) -> Option<Vec<u8>> {
+        self.regions.remove(&address)
+    }
+
+    pub fn clear(&mut self) {
+        self.regions.clear();
+    }
+
+    /// All mapped regions ordered by their address.
+    pub fn regions(&self) -> impl Iterator<Item = (u64, &[u8])> {
+        self.regions
+            .iter()
+            .map(|(address, region)| (*address, region.as_slice()))
+    }
+
+    /// Returns the region containing `address`.
+    pub fn region_at(&self, address: u64) -> Option<(u64, &[u8])> {
+        self.regions
+            .range(..=address)
+            .next_back()
+            .filter(|(region_address, region)| address - **region_address < region.len() as u64)
+            .map(|(region_address, region)| (*region_address, region.as_slice()))
+    }
+
+    pub fn is_mapped(&self, address: u64) -> bool {
+        self.region_at(address).is_some()
+    }
+}
+
+fn region_end(address: u64, len: usize) -> u64 {
+    address
+        .checked_add(len as u64)
+        .expect("region exceeds the address space")
+}
+
+impl MemoryView for SparseMemory {
+    type AccessError = UnmappedMemory;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        let access_len = buffer.len();
+
+        let mut address = offset;
+        let mut buffer = buffer;
+        while !buffer.is_empty() {
+            let (region_address, region) = self.region_at(address).ok_or(UnmappedMemory {
+                access_address: offset,
+                access_len,
+
+                unmapped_address: address,
+            })?;
+
+            let region_offset = (address - region_address) as usize;
+            let count = (region.len() - region_offset).min(buffer.len());
+            buffer[..count].copy_from_slice(&region[region_offset..region_offset + count]);
+
+            buffer = &mut buffer[count..];
+            if buffer.is_empty() {
+                break;
+            }
+
+            /* the access must not wrap around the end of the address space */
+            address = address.checked_add(count as u64).ok_or(UnmappedMemory {
+                access_address: offset,
+                access_len,
+
+                unmapped_address: u64::MAX,
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl MemoryViewDereferenceable for SparseMemory {
+    fn dereference(&self, address: u64) -> Result<u64, Self::AccessError> {
+        Ok(address)
+    }
+}
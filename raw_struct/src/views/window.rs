@@ -0,0 +1,252 @@
+use core::fmt::{
+    self,
+    Debug,
+    Display,
+};
+
+use crate::{
+    MemoryView,
+    MemoryViewDereferenceable,
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum WindowAccessError<E> {
+    /// The access of `len` bytes at `address` is not fully contained within the window.
+    OutOfWindow {
+        address: u64,
+        len: usize,
+    },
+    MemoryAccess(E),
+}
+
+impl<E: Display> fmt::Display for WindowAccessError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfWindow { address, len } => write!(
+                f,
+                "memory access at 0x{:X} (0x{:X} bytes) exceeds the memory window",
+                address, len
+            ),
+            Self::MemoryAccess(inner) => inner.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Display + Debug> std::error::Error for WindowAccessError<E> {}
+
+#[cfg(not(feature = "std"))]
+impl<E: Display + Debug> core::error::Error for WindowAccessError<E> {}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum RebasedAccessError<E> {
+    /// Translating `address` by `delta` exceeds the address space.
+    AddressOverflow {
+        address: u64,
+        delta: i64,
+    },
+    MemoryAccess(E),
+}
+
+impl<E: Display> fmt::Display for RebasedAccessError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddressOverflow { address, delta } => write!(
+                f,
+                "rebasing address 0x{:X} by {} exceeds the address space",
+                address, delta
+            ),
+            Self::MemoryAccess(inner) => inner.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Display + Debug> std::error::Error for RebasedAccessError<E> {}
+
+#[cfg(not(feature = "std"))]
+impl<E: Display + Debug> core::error::Error for RebasedAccessError<E> {}
+
+/// Restricts all reads to the address range `base..base + len` of the underlying view.
+/// Addresses are not translated.
+/// Dereferencing a pointer read through the window references the target within the underlying view.
+///
+/// Created by [`MemoryView::window`].
+#[derive(Debug, Clone, Copy)]
+pub struct WindowMemory<M> {
+    memory: M,
+    base: u64,
+    len: u64,
+}
+
+impl<M: MemoryView> WindowMemory<M> {
+    pub fn new(memory: M, base: u64, len: u64) -> Self {
+        Self { memory, base, len }
+    }
+
+    pub fn base(&self) -> u64 {
+        self.base
+    }
+
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn memory(&self) -> &M {
+        &self.memory
+    }
+
+    pub fn into_inner(self) -> M {
+        self.memory
+    }
+
+    fn contains(&self, address: u64, len: usize) -> bool {
+        let Some(offset) = address.checked_sub(self.base) else {
+            return false;
+        };
+
+        offset
+            .checked_add(len as u64)
+            .is_some_and(|end| end <= self.len)
+    }
+}
+
+impl<M: MemoryView> MemoryView for WindowMemory<M> {
+    type AccessError = WindowAccessError<M::AccessError>;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        if !self.contains(offset, buffer.len()) {
+            return Err(WindowAccessError::OutOfWindow {
+                address: offset,
+                len: buffer.len(),
+            });
+        }
+
+        self.memory
+            .read_memory(offset, buffer)
+            .map_err(WindowAccessError::MemoryAccess)
+    }
+
+    fn read_memory_batch(
+        &self,
+        requests: &mut [(u64, &mut [u8])],
+    ) -> Result<(), Self::AccessError> {
+        if let Some((offset, buffer)) = requests
+            .iter()
+            .find(|(offset, buffer)| !self.contains(*offset, buffer.len()))
+        {
+            return Err(WindowAccessError::OutOfWindow {
+                address: *offset,
+                len: buffer.len(),
+            });
+        }
+
+        self.memory
+            .read_memory_batch(requests)
+            .map_err(WindowAccessError::MemoryAccess)
+    }
+}
+
+/// Translates every address by a fixed delta before reading from the underlying view.
+/// Reading `address` results in reading `address + delta` of the underlying view.
+///
+/// Created by [`MemoryView::rebase`].
+/// ```rust
+/// # use raw_struct::MemoryView;
+/// let dump = [0x11u8, 0x22, 0x33, 0x44];
+/// let memory = dump.as_slice().rebase(-0x1000);
+///
+/// let mut buffer = [0u8; 2];
+/// memory.read_memory(0x1002, &mut buffer).unwrap();
+/// assert_eq!(buffer, [0x33, 0x44]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RebasedMemory<M> {
+    memory: M,
+    delta: i64,
+}
+
+impl<M: MemoryView> RebasedMemory<M> {
+    pub fn new(memory: M, delta: i64) -> Self {
+        Self { memory, delta }
+    }
+
+    pub fn delta(&self) -> i64 {
+        self.delta
+    }
+
+    pub fn memory(&self) -> &M {
+        &self.memory
+    }
+
+    pub fn into_inner(self) -> M {
+        self.memory
+    }
+
+    /// Translate an address of this view into an address of the underlying view.
+    fn translate<E>(&self, address: u64) -> Result<u64, RebasedAccessError<E>> {
+        address
+            .checked_add_signed(self.delta)
+            .ok_or(RebasedAccessError::AddressOverflow {
+                address,
+                delta: self.delta,
+            })
+    }
+
+    /// Translate an address of the underlying view into an address of this view.
+    fn translate_back<E>(&self, address: u64) -> Result<u64, RebasedAccessError<E>> {
+        u64::try_from(address as i128 - self.delta as i128).map_err(|_| {
+            RebasedAccessError::AddressOverflow {
+                address,
+                delta: self.delta,
+            }
+        })
+    }
+}
+
+impl<M: MemoryView> MemoryView for RebasedMemory<M> {
+    type AccessError = RebasedAccessError<M::AccessError>;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        self.memory
+            .read_memory(self.translate(offset)?, buffer)
+            .map_err(RebasedAccessError::MemoryAccess)
+    }
+
+    fn read_memory_batch(
+        &self,
+        requests: &mut [(u64, &mut [u8])],
+    ) -> Result<(), Self::AccessError> {
+        for (offset, _) in requests.iter() {
+            self.translate::<M::AccessError>(*offset)?;
+        }
+
+        for (offset, _) in requests.iter_mut() {
+            *offset = offset.wrapping_add_signed(self.delta);
+        }
+
+        let result = self.memory.read_memory_batch(requests);
+
+        /* every offset has been translated without overflowing, therefore undoing it is exact */
+        for (offset, _) in requests.iter_mut() {
+            *offset = offset.wrapping_add_signed(self.delta.wrapping_neg());
+        }
+
+        result.map_err(RebasedAccessError::MemoryAccess)
+    }
+}
+
+impl<M: MemoryViewDereferenceable> MemoryViewDereferenceable for RebasedMemory<M> {
+    fn dereference(&self, address: u64) -> Result<u64, Self::AccessError> {
+        let address = self
+            .memory
+            .dereference(self.translate(address)?)
+            .map_err(RebasedAccessError::MemoryAccess)?;
+
+        self.translate_back(address)
+    }
+}
@@ -0,0 +1,349 @@
+use core::convert::Infallible;
+
+use crate::{
+    FromMemoryView,
+    MemoryView,
+    MemoryViewDereferenceable,
+    PrimitiveType,
+    TypeInfo,
+    TypeKind,
+    ViewableField,
+};
+
+/// Typed callbacks for every field of a [`Viewable`](crate::Viewable).
+///
+/// All callbacks default to [`FieldVisitor::visit_field`], therefore only the callbacks of interest
+/// need to be implemented. `M` is the memory the visited object lives in.
+/// ```rust
+/// # use raw_struct::{raw_struct, FieldVisitor, MemoryView, ViewableField, Viewable, Reference};
+/// #[raw_struct(size = 0x08)]
+/// struct MyStruct {
+///     #[field(offset = 0x00)]
+///     pub field_a: u32,
+///
+///     #[field(offset = 0x04)]
+///     pub field_b: u32,
+/// }
+///
+/// struct SumVisitor(u64);
+/// impl<M: MemoryView> FieldVisitor<M> for SumVisitor {
+///     fn visit_u32(&mut self, _field: &dyn ViewableField, value: Result<u32, M::AccessError>) {
+///         self.0 += value.map_or(0, u64::from);
+///     }
+/// }
+///
+/// let memory = [0x01u8, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00];
+/// let object = Reference::<MyStruct, _>::new(memory.as_slice(), 0x00);
+///
+/// let mut visitor = SumVisitor(0);
+/// MyStruct::visit_fields(&object, &mut visitor);
+/// assert_eq!(visitor.0, 3);
+/// ```
+pub trait FieldVisitor<M: MemoryView> {
+    /// Fallback for every callback which has not been implemented.
+    fn visit_field(&mut self, _field: &dyn ViewableField) {}
+
+    fn visit_u8(&mut self, field: &dyn ViewableField, _value: Result<u8, M::AccessError>) {
+        self.visit_field(field)
+    }
+
+    fn visit_i8(&mut self, field: &dyn ViewableField, _value: Result<i8, M::AccessError>) {
+        self.visit_field(field)
+    }
+
+    fn visit_u16(&mut self, field: &dyn ViewableField, _value: Result<u16, M::AccessError>) {
+        self.visit_field(field)
+    }
+
+    fn visit_i16(&mut self, field: &dyn ViewableField, _value: Result<i16, M::AccessError>) {
+        self.visit_field(field)
+    }
+
+    fn visit_u32(&mut self, field: &dyn ViewableField, _value: Result<u32, M::AccessError>) {
+        self.visit_field(field)
+    }
+
+    fn visit_i32(&mut self, field: &dyn ViewableField, _value: Result<i32, M::AccessError>) {
+        self.visit_field(field)
+    }
+
+    fn visit_u64(&mut self, field: &dyn ViewableField, _value: Result<u64, M::AccessError>) {
+        self.visit_field(field)
+    }
+
+    fn visit_i64(&mut self, field: &dyn ViewableField, _value: Result<i64, M::AccessError>) {
+        self.visit_field(field)
+    }
+
+    fn visit_f32(&mut self, field: &dyn ViewableField, _value: Result<f32, M::AccessError>) {
+        self.visit_field(field)
+    }
+
+    fn visit_f64(&mut self, field: &dyn ViewableField, _value: Result<f64, M::AccessError>) {
+        self.visit_field(field)
+    }
+
+    fn visit_bool(&mut self, field: &dyn ViewableField, _value: Result<bool, M::AccessError>) {
+        self.visit_field(field)
+    }
+
+    /// Visit a pointer field, e.g. [`Ptr64`](crate::builtins::Ptr64) or [`CompressedPtr32`](crate::builtins::CompressedPtr32).
+    fn visit_ptr(
+        &mut self,
+        field: &dyn ViewableField,
+        _value: Result<PointerValue<'_, M>, M::AccessError>,
+    ) {
+        self.visit_field(field)
+    }
+
+    /// Visit an inline struct field, e.g. [`Copy`](crate::Copy).
+    fn visit_struct(&mut self, field: &dyn ViewableField, _value: StructValue<'_, M>) {
+        self.visit_field(field)
+    }
+
+    fn visit_array(&mut self, field: &dyn ViewableField, _value: ArrayValue<'_, M>) {
+        self.visit_field(field)
+    }
+
+    /// Visit a field without type information or a chained field.
+    fn visit_opaque(&mut self, field: &dyn ViewableField) {
+        self.visit_field(field)
+    }
+}
+
+/// A struct located in memory.
+pub struct StructValue<'a, M> {
+    memory: &'a M,
+    address: u64,
+    type_info: TypeInfo,
+}
+
+impl<'a, M: MemoryView> StructValue<'a, M> {
+    pub fn new(memory: &'a M, address: u64, type_info: TypeInfo) -> Self {
+        Self {
+            memory,
+            address,
+            type_info,
+        }
+    }
+
+    pub fn memory(&self) -> &'a M {
+        self.memory
+    }
+
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn type_info(&self) -> TypeInfo {
+        self.type_info
+    }
+
+    /// Visit all fields of the struct, starting with the inherited fields.
+    pub fn visit_fields<F: FieldVisitor<M> + ?Sized>(&self, visitor: &mut F) {
+        if let Some(base) = self.type_info.base() {
+            StructValue::new(self.memory, self.address, base).visit_fields(visitor);
+        }
+
+        for field in self.type_info.fields().unwrap_or_default() {
+            if !field.chain().is_empty() {
+                visitor.visit_opaque(*field);
+                continue;
+            }
+
+            visit_value(
+                self.memory,
+                *field,
+                field.type_info(),
+                self.address + field.offset(),
+                visitor,
+            );
+        }
+    }
+}
+
+/// A pointer value read from memory.
+pub struct PointerValue<'a, M> {
+    memory: &'a M,
+    raw: u64,
+    address: u64,
+    pointee: TypeInfo,
+}
+
+impl<'a, M: MemoryView> PointerValue<'a, M> {
+    pub fn memory(&self) -> &'a M {
+        self.memory
+    }
+
+    /// The raw pointer value as stored in memory.
+    pub fn raw(&self) -> u64 {
+        self.raw
+    }
+
+    /// The address the pointer points to.
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.raw == 0 || self.address == 0
+    }
+
+    pub fn pointee(&self) -> TypeInfo {
+        self.pointee
+    }
+}
+
+impl<'a, M: MemoryViewDereferenceable> PointerValue<'a, M> {
+    /// The struct the pointer points to.
+    /// Returns `None` if the pointer is null or does not point to a struct.
+    pub fn dereference(&self) -> Result<Option<StructValue<'a, M>>, M::AccessError> {
+        if self.is_null() || !matches!(self.pointee.kind, TypeKind::Struct { .. }) {
+            return Ok(None);
+        }
+
+        let address = self.memory.dereference(self.address)?;
+        Ok(Some(StructValue::new(self.memory, address, self.pointee)))
+    }
+}
+
+/// An inline array located in memory.
+pub struct ArrayValue<'a, M> {
+    memory: &'a M,
+    field: &'a dyn ViewableField,
+    address: u64,
+    element: TypeInfo,
+    len: Option<usize>,
+}
+
+impl<'a, M: MemoryView> ArrayValue<'a, M> {
+    pub fn memory(&self) -> &'a M {
+        self.memory
+    }
+
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn element(&self) -> TypeInfo {
+        self.element
+    }
+
+    pub fn len(&self) -> Option<usize> {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == Some(0)
+    }
+
+    /// Visit the element at `index`.
+    /// The element is passed to the visitor with the array field name and its offset relative to the array.
+    ///
+    /// Elements of unknown size, indices exceeding the array length and elements exceeding
+    /// the address space are visited as opaque.
+    pub fn visit_element<F: FieldVisitor<M> + ?Sized>(&self, index: usize, visitor: &mut F) {
+        let location = self
+            .element
+            .size
+            .filter(|_| self.len.is_none_or(|len| index < len))
+            .and_then(|element_size| index.checked_mul(element_size))
+            .and_then(|offset| {
+                let offset = offset as u64;
+                Some((offset, self.address.checked_add(offset)?))
+            });
+
+        let Some((offset, address)) = location else {
+            visitor.visit_opaque(self.field);
+            return;
+        };
+
+        let element = ArrayElement {
+            name: self.field.name(),
+            offset,
+            type_info: self.element,
+        };
+
+        visit_value(self.memory, &element, self.element, address, visitor);
+    }
+}
+
+/// Field passed to the visitor for elements of an array.
+struct ArrayElement {
+    name: &'static str,
+    offset: u64,
+    type_info: TypeInfo,
+}
+
+impl ViewableField for ArrayElement {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    fn type_info(&self) -> TypeInfo {
+        self.type_info
+    }
+}
+
+fn visit_value<M: MemoryView, F: FieldVisitor<M> + ?Sized>(
+    memory: &M,
+    field: &dyn ViewableField,
+    type_info: TypeInfo,
+    address: u64,
+    visitor: &mut F,
+) {
+    match type_info.kind {
+        TypeKind::Primitive(primitive) => match primitive {
+            PrimitiveType::U8 => visitor.visit_u8(field, read(memory, address)),
+            PrimitiveType::I8 => visitor.visit_i8(field, read(memory, address)),
+            PrimitiveType::U16 => visitor.visit_u16(field, read(memory, address)),
+            PrimitiveType::I16 => visitor.visit_i16(field, read(memory, address)),
+            PrimitiveType::U32 => visitor.visit_u32(field, read(memory, address)),
+            PrimitiveType::I32 => visitor.visit_i32(field, read(memory, address)),
+            PrimitiveType::U64 => visitor.visit_u64(field, read(memory, address)),
+            PrimitiveType::I64 => visitor.visit_i64(field, read(memory, address)),
+            PrimitiveType::F32 => visitor.visit_f32(field, read(memory, address)),
+            PrimitiveType::F64 => visitor.visit_f64(field, read(memory, address)),
+            PrimitiveType::Bool => visitor.visit_bool(field, read(memory, address)),
+        },
+        TypeKind::Pointer { pointee, resolve } => {
+            let raw = match type_info.size {
+                Some(4) => read::<u32, _>(memory, address).map(u64::from),
+                _ => read::<u64, _>(memory, address),
+            };
+
+            let value = raw.map(|raw| PointerValue {
+                memory,
+                raw,
+                address: resolve(raw),
+                pointee: pointee(),
+            });
+            visitor.visit_ptr(field, value);
+        }
+        TypeKind::Array { element, len } => visitor.visit_array(
+            field,
+            ArrayValue {
+                memory,
+                field,
+                address,
+                element: element(),
+                len,
+            },
+        ),
+        TypeKind::Struct { .. } => {
+            visitor.visit_struct(field, StructValue::new(memory, address, type_info))
+        }
+        TypeKind::Opaque => visitor.visit_opaque(field),
+    }
+}
+
+fn read<T: FromMemoryView<DecodeError = Infallible>, M: MemoryView>(
+    memory: &M,
+    address: u64,
+) -> Result<T, M::AccessError> {
+    T::read_object(memory, address).map_err(|err| err.into_access_error())
+}
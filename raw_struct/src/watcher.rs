@@ -0,0 +1,321 @@
+use std::{
+    any::Any,
+    boxed::Box,
+    error::Error,
+    fmt::{
+        self,
+        Debug,
+        Display,
+    },
+    time::Instant,
+    vec::Vec,
+};
+
+use crate::{
+    memory::FromMemoryView,
+    MemoryDecodeError,
+    MemoryView,
+    Reference,
+    TypedViewableField,
+    Viewable,
+    ViewableExtends,
+    ViewableField,
+};
+
+/// Decides whether a change of a watched field should be reported.
+pub trait ChangeFilter<R> {
+    fn matches(&self, old: &R, new: &R) -> bool;
+}
+
+impl<R, F: Fn(&R, &R) -> bool> ChangeFilter<R> for F {
+    fn matches(&self, old: &R, new: &R) -> bool {
+        self(old, new)
+    }
+}
+
+/// Only report changes where the value crosses the threshold in either direction.
+/// Reaching the threshold counts as crossing it.
+#[derive(Debug, Clone, Copy)]
+pub struct Crossing<R>(pub R);
+
+impl<R: PartialOrd> ChangeFilter<R> for Crossing<R> {
+    fn matches(&self, old: &R, new: &R) -> bool {
+        (*old > self.0 && *new <= self.0) || (*old < self.0 && *new >= self.0)
+    }
+}
+
+/// Error returned by [`FieldWatcher::poll`].
+#[derive(Debug)]
+pub enum WatchError<A> {
+    /// The address of `field` exceeds the address space.
+    AddressOverflow {
+        field: &'static str,
+        address: u64,
+        offset: u64,
+    },
+
+    MemoryAccess(A),
+
+    /// The value of `field` could not be decoded.
+    ValueDecode {
+        field: &'static str,
+        error: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl<A: Display> Display for WatchError<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AddressOverflow {
+                field,
+                address,
+                offset,
+            } => write!(
+                f,
+                "address of {} (0x{:X} + 0x{:X}) exceeds the address space",
+                field, address, offset
+            ),
+            Self::MemoryAccess(inner) => inner.fmt(f),
+            Self::ValueDecode { field, error } => {
+                write!(f, "failed to decode {}: {}", field, error)
+            }
+        }
+    }
+}
+
+impl<A: Display + Debug> Error for WatchError<A> {}
+
+trait WatchValue: Any + Debug {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Any + Debug> WatchValue for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A change of a watched field.
+pub struct ChangeEvent {
+    pub field: &'static str,
+    pub offset: u64,
+    pub timestamp: Instant,
+    old: Box<dyn WatchValue>,
+    new: Box<dyn WatchValue>,
+}
+
+impl ChangeEvent {
+    /// Returns true if the event has been emitted for `field`.
+    pub fn is<C, R>(&self, field: &TypedViewableField<C, R>) -> bool {
+        self.field == field.name() && self.offset == field.offset()
+    }
+
+    /// The previous value or `None` if the field is not of type `R`.
+    pub fn old_value<R: 'static>(&self) -> Option<&R> {
+        (*self.old).as_any().downcast_ref()
+    }
+
+    /// The new value or `None` if the field is not of type `R`.
+    pub fn new_value<R: 'static>(&self) -> Option<&R> {
+        (*self.new).as_any().downcast_ref()
+    }
+}
+
+impl Debug for ChangeEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChangeEvent")
+            .field("field", &self.field)
+            .field("offset", &self.offset)
+            .field("old", &self.old)
+            .field("new", &self.new)
+            .field("timestamp", &self.timestamp)
+            .finish()
+    }
+}
+
+trait WatchEntry<T, M: MemoryView> {
+    /// Read the current value without updating the known value.
+    fn read(&mut self, reference: &Reference<T, M>) -> Result<(), WatchError<M::AccessError>>;
+
+    /// Update the known value with the value previously read.
+    fn commit(&mut self, timestamp: Instant) -> Option<ChangeEvent>;
+}
+
+struct WatchedField<C: 'static, R: 'static> {
+    field: &'static TypedViewableField<C, R>,
+    filter: Option<Box<dyn ChangeFilter<R>>>,
+    current: Option<R>,
+    next: Option<R>,
+}
+
+impl<T, M, C, R> WatchEntry<T, M> for WatchedField<C, R>
+where
+    T: Viewable + ViewableExtends<C>,
+    M: MemoryView,
+    R: FromMemoryView + PartialEq + Clone + Debug,
+    R::DecodeError: Error + Send + Sync + 'static,
+{
+    fn read(&mut self, reference: &Reference<T, M>) -> Result<(), WatchError<M::AccessError>> {
+        let address = reference.memory_address();
+        let offset = self.field.offset();
+        let field_address = address
+            .checked_add(offset)
+            .ok_or(WatchError::AddressOverflow {
+                field: self.field.name(),
+                address,
+                offset,
+            })?;
+
+        let value = R::read_object(reference.memory(), field_address).map_err(|err| match err {
+            MemoryDecodeError::MemoryAccess(error) => WatchError::MemoryAccess(error),
+            MemoryDecodeError::ValueDecode(error) => WatchError::ValueDecode {
+                field: self.field.name(),
+                error: Box::new(error),
+            },
+        })?;
+
+        self.next = Some(value);
+        Ok(())
+    }
+
+    fn commit(&mut self, timestamp: Instant) -> Option<ChangeEvent> {
+        let new = self.next.take()?;
+        let old = self.current.replace(new.clone())?;
+        if old == new {
+            return None;
+        }
+
+        if let Some(filter) = &self.filter {
+            if !filter.matches(&old, &new) {
+                return None;
+            }
+        }
+
+        Some(ChangeEvent {
+            field: self.field.name(),
+            offset: self.field.offset(),
+            timestamp,
+            old: Box::new(old),
+            new: Box::new(new),
+        })
+    }
+}
+
+/// Watches a set of fields of an object and reports their changes.
+///
+/// The first call to [`FieldWatcher::poll`] records the initial values and does not report any changes.
+/// ```rust
+/// # use std::cell::Cell;
+/// # use raw_struct::{raw_struct, Crossing, FieldWatcher, MemoryView, OutOfBoundsViolation, Reference};
+/// #[raw_struct(size = 0x08)]
+/// struct Player {
+///     #[field(offset = 0x00)]
+///     pub health: u32,
+///
+///     #[field(offset = 0x04)]
+///     pub ammo: u32,
+/// }
+///
+/// # struct CellMemory(Cell<[u8; 8]>);
+/// # impl MemoryView for CellMemory {
+/// #     type AccessError = OutOfBoundsViolation;
+/// #     fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+/// #         self.0.get().as_slice().read_memory(offset, buffer)
+/// #     }
+/// # }
+/// let memory = CellMemory(Cell::new([100, 0, 0, 0, 30, 0, 0, 0]));
+/// let mut watcher = FieldWatcher::new(Reference::<Player, _>::new(&memory, 0x00))
+///     .with_field(Player::ammo)
+///     .with_filtered_field(Player::health, Crossing(50));
+/// assert!(watcher.poll()?.is_empty());
+///
+/// memory.0.set([40, 0, 0, 0, 29, 0, 0, 0]);
+/// let events = watcher.poll()?;
+/// assert_eq!(events.len(), 2);
+/// assert!(events[0].is(Player::ammo));
+/// assert_eq!(events[0].new_value::<u32>(), Some(&29));
+/// assert_eq!(events[1].old_value::<u32>(), Some(&100));
+/// # Ok::<(), raw_struct::WatchError<OutOfBoundsViolation>>(())
+/// ```
+pub struct FieldWatcher<T, M: MemoryView> {
+    reference: Reference<T, M>,
+    fields: Vec<Box<dyn WatchEntry<T, M>>>,
+}
+
+impl<T: Viewable, M: MemoryView> FieldWatcher<T, M> {
+    pub fn new(reference: Reference<T, M>) -> Self {
+        Self {
+            reference,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Report every change of `field`.
+    pub fn with_field<C: 'static, R: FromMemoryView + PartialEq + Clone + Debug + 'static>(
+        mut self,
+        field: &'static TypedViewableField<C, R>,
+    ) -> Self
+    where
+        T: ViewableExtends<C>,
+        R::DecodeError: Error + Send + Sync + 'static,
+    {
+        self.push_field(field, None);
+        self
+    }
+
+    /// Report changes of `field` which match the filter.
+    pub fn with_filtered_field<
+        C: 'static,
+        R: FromMemoryView + PartialEq + Clone + Debug + 'static,
+    >(
+        mut self,
+        field: &'static TypedViewableField<C, R>,
+        filter: impl ChangeFilter<R> + 'static,
+    ) -> Self
+    where
+        T: ViewableExtends<C>,
+        R::DecodeError: Error + Send + Sync + 'static,
+    {
+        self.push_field(field, Some(Box::new(filter)));
+        self
+    }
+
+    fn push_field<C: 'static, R: FromMemoryView + PartialEq + Clone + Debug + 'static>(
+        &mut self,
+        field: &'static TypedViewableField<C, R>,
+        filter: Option<Box<dyn ChangeFilter<R>>>,
+    ) where
+        T: ViewableExtends<C>,
+        R::DecodeError: Error + Send + Sync + 'static,
+    {
+        self.fields.push(Box::new(WatchedField {
+            field,
+            filter,
+            current: None,
+            next: None,
+        }));
+    }
+}
+
+impl<T, M: MemoryView> FieldWatcher<T, M> {
+    pub fn reference(&self) -> &Reference<T, M> {
+        &self.reference
+    }
+
+    /// Read all watched fields and return the changes since the last poll in the order
+    /// the fields have been added.
+    ///
+    /// If reading any field fails, no values are updated.
+    pub fn poll(&mut self) -> Result<Vec<ChangeEvent>, WatchError<M::AccessError>> {
+        for field in self.fields.iter_mut() {
+            field.read(&self.reference)?;
+        }
+
+        let timestamp = Instant::now();
+        Ok(self
+            .fields
+            .iter_mut()
+            .filter_map(|field| field.commit(timestamp))
+            .collect())
+    }
+}
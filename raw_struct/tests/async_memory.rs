@@ -0,0 +1,197 @@
+#![cfg(feature = "alloc")]
+
+use std::{
+    future::Future,
+    pin::pin,
+    task::{
+        Context,
+        Poll,
+        Waker,
+    },
+};
+
+use raw_struct::{
+    builtins::Ptr64,
+    raw_struct,
+    views::{
+        SparseMemory,
+        UnmappedMemory,
+    },
+    AsyncAdapter,
+    AsyncMemoryView,
+    AsyncMemoryViewDereferenceable,
+    Copy,
+    MemoryDecodeError,
+    MemoryView,
+    OutOfBoundsViolation,
+    Reference,
+};
+
+#[raw_struct(size = 0x10)]
+struct Node {
+    #[field(offset = 0x00)]
+    pub value: u32,
+
+    #[field(offset = 0x08)]
+    pub next: Ptr64<Node>,
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(result) = future.as_mut().poll(&mut context) {
+            return result;
+        }
+    }
+}
+
+/// Memory view which only completes reads after being polled once more.
+struct RemoteMemory(Vec<u8>);
+
+impl AsyncMemoryView for RemoteMemory {
+    type AccessError = OutOfBoundsViolation;
+
+    async fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        let mut yielded = false;
+        std::future::poll_fn(|context| {
+            if yielded {
+                Poll::Ready(())
+            } else {
+                yielded = true;
+                context.waker().wake_by_ref();
+                Poll::Pending
+            }
+        })
+        .await;
+
+        self.0.as_slice().read_memory(offset, buffer)
+    }
+}
+
+impl AsyncMemoryViewDereferenceable for RemoteMemory {
+    async fn dereference(&self, address: u64) -> Result<u64, Self::AccessError> {
+        Ok(address)
+    }
+}
+
+fn create_memory() -> Vec<u8> {
+    let mut memory = vec![0u8; 0x20];
+    memory[0x00..0x04].copy_from_slice(&0x11u32.to_le_bytes());
+    memory[0x08..0x10].copy_from_slice(&0x10u64.to_le_bytes());
+    memory[0x10..0x14].copy_from_slice(&0x22u32.to_le_bytes());
+    memory
+}
+
+#[test]
+fn test_async_reference() {
+    let memory = RemoteMemory(create_memory());
+    let node = Reference::<Node, _>::new(&memory, 0x00);
+
+    block_on(async {
+        assert_eq!(node.read_field_async(Node::value).await, Ok(0x11));
+
+        let next = node.dereference_field_async(Node::next).await.unwrap();
+        assert_eq!(next.memory_address(), 0x10);
+        assert_eq!(next.read_field_async(Node::value).await, Ok(0x22));
+
+        let copy = next.create_copy_async().await.unwrap();
+        assert_eq!(copy.read_field(Node::value), Ok(0x22));
+        assert!(copy.read_field(Node::next).unwrap().is_null());
+
+        let value = Ptr64::<u32>::from_address(0x10)
+            .read_value_async(&memory)
+            .await;
+        assert_eq!(value, Ok(Some(0x22)));
+
+        let value = Ptr64::<u32>::from_address(0x00)
+            .read_value_async(&memory)
+            .await;
+        assert_eq!(value, Ok(None));
+
+        let value = Ptr64::<Copy<Node>>::from_address(0x10)
+            .read_value_async(&memory)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(value.read_field(Node::value), Ok(0x22));
+    });
+}
+
+#[test]
+fn test_async_adapter() {
+    let mut memory = SparseMemory::new();
+    memory.insert(0x1000, create_memory()).unwrap();
+
+    let memory = AsyncAdapter(memory);
+    let node = Reference::<Node, _>::new(&memory, 0x1000);
+
+    block_on(async {
+        assert_eq!(node.read_field_async(Node::value).await, Ok(0x11));
+
+        let next = node.dereference_field_async(Node::next).await.unwrap();
+        assert_eq!(
+            next.read_field_async(Node::value).await,
+            Err(MemoryDecodeError::MemoryAccess(UnmappedMemory {
+                access_address: 0x10,
+                access_len: 4,
+                unmapped_address: 0x10
+            }))
+        );
+    });
+}
+
+#[test]
+fn test_async_decoded_fields() {
+    #[raw_struct(size = 0x18)]
+    struct Container {
+        #[field(offset = 0x00)]
+        pub active: bool,
+
+        #[field(offset = 0x08)]
+        pub node: Copy<Node>,
+    }
+
+    let mut memory = vec![0u8; 0x18];
+    memory[0x00] = 0x02;
+    memory[0x08..0x18].copy_from_slice(&create_memory()[0x00..0x10]);
+
+    let memory = RemoteMemory(memory);
+    let container = Reference::<Container, _>::new(&memory, 0x00);
+
+    block_on(async {
+        assert_eq!(
+            container.read_field_async(Container::active).await,
+            Ok(true)
+        );
+
+        let node = container.read_field_async(Container::node).await.unwrap();
+        assert_eq!(node.read_field(Node::value), Ok(0x11));
+        assert_eq!(node.read_field(Node::next).unwrap().address(), 0x10);
+    });
+}
+
+#[test]
+fn test_async_owned_views() {
+    use std::rc::Rc;
+
+    let memory = Box::new(RemoteMemory(create_memory()));
+    let node = Reference::<Node, _>::new(&memory, 0x00);
+    block_on(async {
+        let next = node.dereference_field_async(Node::next).await.unwrap();
+        assert_eq!(next.read_field_async(Node::value).await, Ok(0x22));
+    });
+
+    let memory = Rc::new(RemoteMemory(create_memory()));
+    let node = Reference::<Node, _>::new(memory.clone(), 0x00);
+    block_on(async {
+        let next = node.dereference_field_async(Node::next).await.unwrap();
+        assert_eq!(next.read_field_async(Node::value).await, Ok(0x22));
+        assert_eq!(
+            Ptr64::<bool>::from_address(0x10)
+                .read_value_async(&memory)
+                .await,
+            Ok(Some(true))
+        );
+    });
+}
@@ -0,0 +1,110 @@
+use std::cell::Cell;
+
+use raw_struct::{
+    raw_struct,
+    MemoryView,
+    OutOfBoundsViolation,
+    Reference,
+};
+
+#[raw_struct(size = 0x20)]
+struct BaseStruct {
+    #[field(offset = 0x00)]
+    pub health: u32,
+}
+
+#[raw_struct(size = 0x20, inherits = "BaseStruct")]
+struct Player {
+    #[field(offset = 0x04)]
+    pub armor: u16,
+
+    #[field(offset = 0x08)]
+    pub position: [f32; 3],
+
+    #[field(offset = 0x18)]
+    pub team: u8,
+}
+
+struct BatchMemory<'a> {
+    memory: &'a [u8],
+    reads: Cell<usize>,
+    batches: Cell<usize>,
+}
+
+impl MemoryView for BatchMemory<'_> {
+    type AccessError = OutOfBoundsViolation;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        self.reads.set(self.reads.get() + 1);
+        self.memory.read_memory(offset, buffer)
+    }
+
+    fn read_memory_batch(
+        &self,
+        requests: &mut [(u64, &mut [u8])],
+    ) -> Result<(), Self::AccessError> {
+        self.batches.set(self.batches.get() + 1);
+        for (offset, buffer) in requests.iter_mut() {
+            self.memory.read_memory(*offset, buffer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_read_fields() {
+    let mut memory = [0u8; 0x40];
+    memory[0x20..0x24].copy_from_slice(&100u32.to_le_bytes());
+    memory[0x24..0x26].copy_from_slice(&50u16.to_le_bytes());
+    memory[0x2C..0x30].copy_from_slice(&1.5f32.to_le_bytes());
+    memory[0x38] = 2;
+
+    let memory = BatchMemory {
+        memory: &memory,
+        reads: Cell::new(0),
+        batches: Cell::new(0),
+    };
+
+    let player = Reference::<Player, _>::new(&memory, 0x20);
+    let (health, armor, position, team) = player
+        .read_fields((
+            BaseStruct::health,
+            Player::armor,
+            Player::position,
+            Player::team,
+        ))
+        .unwrap();
+
+    assert_eq!(health, 100);
+    assert_eq!(armor, 50);
+    assert_eq!(position, [0.0, 1.5, 0.0]);
+    assert_eq!(team, 2);
+
+    assert_eq!(memory.batches.get(), 1);
+    assert_eq!(memory.reads.get(), 0);
+
+    let player = Reference::<Player, _>::new(&memory, 0x30);
+    assert_eq!(
+        player.read_fields((Player::armor, Player::team)),
+        Err(OutOfBoundsViolation {
+            access_offset: 0x48,
+            access_len: 1,
+            src_len: 0x40
+        })
+    );
+}
+
+#[test]
+fn test_read_fields_default_batch() {
+    let mut memory = [0u8; 0x20];
+    memory[0x04..0x06].copy_from_slice(&50u16.to_le_bytes());
+    memory[0x18] = 2;
+
+    let memory = memory.as_slice().window(0x00, 0x20).rebase(0x00);
+    let player = Reference::<Player, _>::new(&memory, 0x00);
+    assert_eq!(
+        player.read_fields((Player::armor, Player::team)),
+        Ok((50, 2))
+    );
+}
@@ -0,0 +1,240 @@
+#![cfg(feature = "alloc")]
+
+use std::{
+    cell::{
+        Cell,
+        OnceCell,
+        RefCell,
+    },
+    rc::{
+        Rc,
+        Weak,
+    },
+};
+
+use raw_struct::{
+    raw_struct,
+    views::CachedMemory,
+    MemoryView,
+    OutOfBoundsViolation,
+    Reference,
+};
+
+#[raw_struct(size = 0x10)]
+struct Dummy {
+    #[field(offset = 0x00)]
+    pub field_a: u32,
+
+    #[field(offset = 0x04)]
+    pub field_b: u32,
+
+    #[field(offset = 0x08)]
+    pub field_c: u64,
+}
+
+struct CountingMemory {
+    memory: RefCell<Vec<u8>>,
+    reads: Cell<usize>,
+}
+
+impl CountingMemory {
+    fn new(len: usize) -> Self {
+        Self {
+            memory: RefCell::new(vec![0u8; len]),
+            reads: Cell::new(0),
+        }
+    }
+
+    fn write(&self, address: usize, data: &[u8]) {
+        self.memory.borrow_mut()[address..address + data.len()].copy_from_slice(data);
+    }
+}
+
+impl MemoryView for CountingMemory {
+    type AccessError = OutOfBoundsViolation;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        self.reads.set(self.reads.get() + 1);
+        self.memory.borrow().as_slice().read_memory(offset, buffer)
+    }
+}
+
+#[test]
+fn test_cached_reads() {
+    let backend = CountingMemory::new(0x100);
+    backend.write(0x38, &0x11u32.to_le_bytes());
+    backend.write(0x40, &0x22u64.to_le_bytes());
+
+    let memory = CachedMemory::with_page_size(&backend, 0x40);
+    let object = Reference::<Dummy, _>::new(&memory, 0x38);
+    assert_eq!(object.read_field(Dummy::field_a), Ok(0x11));
+    assert_eq!(object.read_field(Dummy::field_b), Ok(0x00));
+    assert_eq!(object.read_field(Dummy::field_c), Ok(0x22));
+    assert_eq!(
+        object.create_copy().unwrap().read_field(Dummy::field_c),
+        Ok(0x22)
+    );
+
+    /* page 0x00 and page 0x40 */
+    assert_eq!(backend.reads.get(), 2);
+    assert_eq!(memory.cached_pages(), 2);
+}
+
+#[test]
+fn test_cached_invalidation() {
+    let backend = CountingMemory::new(0x100);
+    let memory = CachedMemory::with_page_size(&backend, 0x40);
+    let object = Reference::<Dummy, _>::new(&memory, 0x80);
+    assert_eq!(object.read_field(Dummy::field_a), Ok(0x00));
+
+    backend.write(0x80, &0x11u32.to_le_bytes());
+    assert_eq!(object.read_field(Dummy::field_a), Ok(0x00));
+
+    memory.invalidate_range(0x70, 0x11);
+    assert_eq!(object.read_field(Dummy::field_a), Ok(0x11));
+
+    backend.write(0x80, &0x22u32.to_le_bytes());
+    memory.invalidate_all();
+    assert_eq!(memory.generation(), 1);
+    assert_eq!(memory.cached_pages(), 0);
+    assert_eq!(object.read_field(Dummy::field_a), Ok(0x22));
+    assert_eq!(backend.reads.get(), 3);
+}
+
+#[test]
+fn test_cached_partial_page() {
+    /* the last page can not be fetched as a whole */
+    let backend = CountingMemory::new(0x60);
+    backend.write(0x50, &0x11u32.to_le_bytes());
+
+    let memory = CachedMemory::with_page_size(&backend, 0x40);
+    let object = Reference::<Dummy, _>::new(&memory, 0x50);
+    assert_eq!(object.read_field(Dummy::field_a), Ok(0x11));
+    assert_eq!(memory.cached_pages(), 0);
+
+    let object = Reference::<Dummy, _>::new(&memory, 0x58);
+    assert_eq!(
+        object
+            .read_field(Dummy::field_c)
+            .unwrap_err()
+            .into_access_error(),
+        OutOfBoundsViolation {
+            access_offset: 0x60,
+            access_len: 0x08,
+            src_len: 0x60
+        }
+    );
+}
+
+#[test]
+fn test_cached_failed_page() {
+    let backend = CountingMemory::new(0x60);
+    backend.write(0x50, &0x11u32.to_le_bytes());
+
+    let memory = CachedMemory::with_page_size(&backend, 0x40);
+    let object = Reference::<Dummy, _>::new(&memory, 0x50);
+    assert_eq!(object.read_field(Dummy::field_a), Ok(0x11));
+    assert_eq!(backend.reads.get(), 2);
+
+    /* the failed page fetch is not repeated within the same generation */
+    assert_eq!(object.read_field(Dummy::field_a), Ok(0x11));
+    assert_eq!(backend.reads.get(), 3);
+
+    memory.invalidate_all();
+    assert_eq!(object.read_field(Dummy::field_a), Ok(0x11));
+    assert_eq!(backend.reads.get(), 5);
+}
+
+#[test]
+fn test_cached_batch() {
+    let backend = CountingMemory::new(0x100);
+    backend.write(0x38, &0x11u32.to_le_bytes());
+    backend.write(0x40, &0x22u64.to_le_bytes());
+
+    let memory = CachedMemory::with_page_size(&backend, 0x40);
+    let object = Reference::<Dummy, _>::new(&memory, 0x38);
+    assert_eq!(
+        object.read_fields((Dummy::field_a, Dummy::field_c)),
+        Ok((0x11, 0x22))
+    );
+    assert_eq!(memory.cached_pages(), 2);
+    assert_eq!(backend.reads.get(), 2);
+
+    assert_eq!(object.read_field(Dummy::field_b), Ok(0x00));
+    assert_eq!(backend.reads.get(), 2);
+}
+
+#[test]
+fn test_cached_reentrant_read() {
+    /// Inspects the cache while being read from.
+    struct ReentrantMemory {
+        cache: OnceCell<Weak<CachedMemory<ReentrantMemory>>>,
+        observed_pages: Cell<usize>,
+    }
+
+    impl MemoryView for ReentrantMemory {
+        type AccessError = OutOfBoundsViolation;
+
+        fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+            if let Some(cache) = self.cache.get().and_then(Weak::upgrade) {
+                self.observed_pages.set(cache.cached_pages());
+            }
+
+            [0x11u8; 0x100].as_slice().read_memory(offset, buffer)
+        }
+    }
+
+    let memory = Rc::new_cyclic(|cache| {
+        let backend = ReentrantMemory {
+            cache: OnceCell::from(cache.clone()),
+            observed_pages: Cell::new(usize::MAX),
+        };
+
+        CachedMemory::with_page_size(backend, 0x40)
+    });
+
+    let object = Reference::<Dummy, _>::new(memory.clone(), 0x40);
+    assert_eq!(object.read_field(Dummy::field_a), Ok(0x1111_1111));
+    assert_eq!(memory.memory().observed_pages.get(), 0);
+    assert_eq!(memory.cached_pages(), 1);
+}
+
+#[test]
+fn test_cached_address_space_end() {
+    /// Records all reads and rejects reads exceeding the address space.
+    struct RecordingMemory {
+        reads: RefCell<Vec<(u64, usize)>>,
+    }
+
+    impl MemoryView for RecordingMemory {
+        type AccessError = OutOfBoundsViolation;
+
+        fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+            self.reads.borrow_mut().push((offset, buffer.len()));
+            if offset.checked_add(buffer.len() as u64).is_none() {
+                return Err(OutOfBoundsViolation {
+                    access_offset: offset as usize,
+                    access_len: buffer.len(),
+                    src_len: usize::MAX,
+                });
+            }
+
+            buffer.fill(0x11);
+            Ok(())
+        }
+    }
+
+    let backend = RecordingMemory {
+        reads: Default::default(),
+    };
+    let memory = CachedMemory::with_page_size(&backend, 0x40);
+
+    let mut buffer = [0u8; 0x08];
+    assert!(memory.read_memory(u64::MAX - 0x03, &mut buffer).is_err());
+    assert_eq!(*backend.reads.borrow(), [(u64::MAX - 0x03, 0x08)]);
+
+    backend.reads.borrow_mut().clear();
+    assert_eq!(memory.read_memory(u64::MAX - 0x47, &mut buffer), Ok(()));
+    assert_eq!(buffer, [0x11; 0x08]);
+    assert_eq!(*backend.reads.borrow(), [(u64::MAX - 0x7F, 0x40)]);
+}
@@ -0,0 +1,151 @@
+#![cfg(feature = "alloc")]
+
+use raw_struct::{
+    builtins::{
+        CompressedPtr32,
+        ConstCompression,
+        PointerCompression,
+    },
+    raw_struct,
+    views::SparseMemory,
+    Reference,
+};
+
+type JavaHeap = ConstCompression<0x8_0000_0000, 3>;
+
+#[raw_struct(size = 0x10)]
+struct JavaObject {
+    #[field(offset = 0x08)]
+    pub value: u32,
+
+    #[field(offset = 0x0C)]
+    pub next: CompressedPtr32<JavaObject, JavaHeap>,
+}
+
+#[test]
+fn test_compressed_pointer() {
+    let ptr = CompressedPtr32::<u32, JavaHeap>::from_value(0x200);
+    assert_eq!(ptr.address(), 0x8_0000_1000);
+    assert_eq!(ptr.address_with(0x1000, 0), 0x1200);
+    assert_eq!(ptr.address_with(0x1000, 63), 0x1000);
+    assert_eq!(ptr.address_with(0x1000, 64), 0x00);
+    assert_eq!(ptr.address_with(0x1000, u32::MAX), 0x00);
+    assert!(CompressedPtr32::<u32, JavaHeap>::from_value(0).is_null());
+}
+
+#[test]
+fn test_compressed_dereference() {
+    let mut object_a = [0u8; 0x10];
+    object_a[0x08..0x0C].copy_from_slice(&0x11u32.to_le_bytes());
+    object_a[0x0C..0x10].copy_from_slice(&0x202u32.to_le_bytes());
+
+    let mut object_b = [0u8; 0x10];
+    object_b[0x08..0x0C].copy_from_slice(&0x22u32.to_le_bytes());
+
+    let mut memory = SparseMemory::new();
+    memory.insert(0x8_0000_1000, object_a).unwrap();
+    memory.insert(0x8_0000_1010, object_b).unwrap();
+
+    let object = Reference::<JavaObject, _>::new(&memory, 0x8_0000_1000);
+    let next = object.read_field(JavaObject::next).unwrap();
+    assert_eq!(next.address(), 0x8_0000_1010);
+    assert_eq!(
+        next.copy_value(&memory)
+            .unwrap()
+            .unwrap()
+            .read_field(JavaObject::value),
+        Ok(0x22)
+    );
+    assert_eq!(
+        next.reference_value(&memory)
+            .unwrap()
+            .read_field(JavaObject::value),
+        Ok(0x22)
+    );
+    assert_eq!(next.cast::<u32>().read_value(&memory), Ok(Some(0x00)));
+
+    let next = object
+        .reference_field(JavaObject::next)
+        .dereference()
+        .unwrap();
+    assert_eq!(next.read_field(JavaObject::value), Ok(0x22));
+
+    let last = next.read_field(JavaObject::next).unwrap();
+    assert!(last.reference_value(&memory).is_none());
+
+    let next = object.dereference_field(JavaObject::next).unwrap();
+    assert_eq!(next.memory_address(), 0x8_0000_1010);
+    assert_eq!(next.read_field(JavaObject::value), Ok(0x22));
+}
+
+#[test]
+fn test_compressed_runtime_context() {
+    struct Isolate {
+        heap_base: u64,
+    }
+
+    impl PointerCompression for Isolate {
+        fn base(&self) -> u64 {
+            self.heap_base
+        }
+
+        fn shift(&self) -> u32 {
+            2
+        }
+    }
+
+    #[raw_struct(size = 0x08)]
+    struct HeapObject {
+        #[field(offset = 0x00)]
+        pub value: u32,
+
+        #[field(offset = 0x04)]
+        pub next: CompressedPtr32<HeapObject, Isolate>,
+    }
+
+    let mut object = [0u8; 0x08];
+    object[0x00..0x04].copy_from_slice(&0x11u32.to_le_bytes());
+    object[0x04..0x08].copy_from_slice(&0x02u32.to_le_bytes());
+
+    /* two isolates with identical heap contents */
+    let mut memory = SparseMemory::new();
+    memory.insert(0x1_0000_0000, object).unwrap();
+    memory
+        .insert(0x1_0000_0008, [0x22, 0, 0, 0, 0, 0, 0, 0])
+        .unwrap();
+    memory.insert(0x2_0000_0000, object).unwrap();
+    memory
+        .insert(0x2_0000_0008, [0x33, 0, 0, 0, 0, 0, 0, 0])
+        .unwrap();
+
+    for (heap_base, expected) in [(0x1_0000_0000, 0x22), (0x2_0000_0000, 0x33)] {
+        let isolate = Isolate { heap_base };
+        let object = Reference::<HeapObject, _>::new(&memory, heap_base);
+        let next = object
+            .reference_field(HeapObject::next)
+            .dereference_in(&isolate)
+            .unwrap();
+
+        assert_eq!(next.memory_address(), heap_base + 0x08);
+        assert_eq!(next.read_field(HeapObject::value), Ok(expected));
+
+        let next = object.read_field(HeapObject::next).unwrap();
+        assert_eq!(
+            next.cast::<u32>().read_value_in(&isolate, &memory),
+            Ok(Some(expected))
+        );
+        assert_eq!(
+            next.reference_value_in(&isolate, &memory)
+                .unwrap()
+                .read_field(HeapObject::value),
+            Ok(expected)
+        );
+        assert_eq!(
+            next.copy_value_in(&isolate, &memory)
+                .unwrap()
+                .unwrap()
+                .read_field(HeapObject::value),
+            Ok(expected)
+        );
+    }
+}
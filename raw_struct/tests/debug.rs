@@ -0,0 +1,113 @@
+#![cfg(feature = "alloc")]
+
+use raw_struct::{
+    builtins::Ptr64,
+    raw_struct,
+    views::MemoryImageBuilder,
+    Copy,
+    Reference,
+};
+
+#[raw_struct(size = 0x08)]
+struct Position {
+    #[field(offset = 0x00)]
+    pub x: f32,
+
+    #[field(offset = 0x04)]
+    pub y: f32,
+}
+
+#[raw_struct(size = 0x10)]
+struct Entity {
+    #[field(offset = 0x00)]
+    pub id: u32,
+
+    #[field(offset = 0x08)]
+    pub position: Copy<Position>,
+}
+
+#[raw_struct(size = 0x28, inherits = "Entity")]
+struct Player {
+    #[field(offset = 0x10)]
+    pub ammo: [u16; 2],
+
+    #[field(offset = 0x18)]
+    pub target: Ptr64<Player>,
+
+    #[field(offset = 0x20)]
+    pub handle: Ptr64<()>,
+}
+
+#[test]
+fn test_debug_copy() {
+    let mut memory = [0u8; 0x10];
+    memory[0x00..0x04].copy_from_slice(&7u32.to_le_bytes());
+    memory[0x08..0x0C].copy_from_slice(&1.5f32.to_le_bytes());
+
+    let entity = Copy::<Entity>::new(memory);
+    assert_eq!(
+        format!("{:?}", entity),
+        "Entity @ 0x0 { id: [0x00] 7, position: [0x08] Position @ 0x8 { x: [0x00] 1.5, y: [0x04] 0.0 } }"
+    );
+
+    assert_eq!(
+        format!("{:#?}", entity),
+        r#"Entity @ 0x0 {
+    id: [0x00] 7,
+    position: [0x08] Position @ 0x8 {
+        x: [0x00] 1.5,
+        y: [0x04] 0.0,
+    },
+}"#
+    );
+}
+
+#[test]
+fn test_debug_follow_pointers() {
+    let mut builder = MemoryImageBuilder::with_base_address(0x1000);
+    let player_a = builder.allocate::<Player>().unwrap();
+    let player_b = builder.allocate::<Player>().unwrap();
+    builder.set_field(&player_a, Entity::id, 1);
+    builder.set_field(&player_a, Player::ammo, [10, 20]);
+    builder.set_pointer(&player_a, Player::target, &player_b);
+    builder.set_field(&player_a, Player::handle, Ptr64::from_address(0xDEAD));
+    builder.set_field(&player_b, Entity::id, 2);
+    builder.set_pointer(&player_b, Player::target, &player_a);
+    let memory = builder.build();
+
+    let player = Reference::<Player, _>::new(&memory, player_a.address());
+    assert_eq!(
+        format!("{:?}", player),
+        "Player @ 0x1000 { id: [0x00] 1, position: [0x08] Position @ 0x1008 { x: [0x00] 0.0, y: [0x04] 0.0 }, \
+         ammo: [0x10] [10, 20], target: [0x18] 0x1030, handle: [0x20] 0xDEAD }"
+    );
+
+    let output = format!("{:?}", player.debug_fields().follow_pointers(2));
+    assert!(output.starts_with(
+        "Player @ 0x1000 { id: [0x00] 1, position: [0x08] Position @ 0x1008 { x: [0x00] 0.0, y: [0x04] 0.0 }, \
+         ammo: [0x10] [10, 20], target: [0x18] -> Player @ 0x1030 { id: [0x00] 2,"
+    ));
+
+    /* the second level is followed but not the third one */
+    assert!(output.contains("target: [0x18] -> Player @ 0x1000 { id: [0x00] 1,"));
+    assert!(output.contains("target: [0x18] 0x1030"));
+}
+
+#[test]
+fn test_debug_errors() {
+    let mut builder = MemoryImageBuilder::with_base_address(0x1000);
+    let player = builder.allocate::<Player>().unwrap();
+    builder.set_field(&player, Player::target, Ptr64::from_address(0x8000));
+    let memory = builder.build();
+
+    let player = Reference::<Player, _>::new(&memory, player.address());
+    let output = format!("{:?}", player.debug_fields().follow_pointers(1));
+    assert!(
+        output.contains("target: [0x18] -> Player @ 0x8000 { id: [0x00] <error: UnmappedMemory"),
+        "{}",
+        output
+    );
+
+    let output = format!("{:?}", player.cast::<Entity>());
+    assert!(output.starts_with("Entity @ 0x1000"));
+}
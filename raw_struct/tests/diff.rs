@@ -0,0 +1,120 @@
+#![cfg(feature = "alloc")]
+
+use raw_struct::{
+    raw_struct,
+    ByteChange,
+    Copy,
+    FieldChange,
+    Reference,
+};
+
+#[raw_struct(size = 0x08)]
+struct Position {
+    #[field(offset = 0x00)]
+    pub x: f32,
+
+    #[field(offset = 0x04)]
+    pub y: f32,
+}
+
+#[raw_struct(size = 0x20)]
+struct Entity {
+    #[field(offset = 0x00)]
+    pub id: u32,
+
+    #[field(offset = 0x08)]
+    pub position: Copy<Position>,
+
+    #[field(offset = 0x10)]
+    pub health: u16,
+}
+
+fn entity_memory(id: u32, x: f32, health: u16) -> [u8; 0x20] {
+    let mut memory = [0u8; 0x20];
+    memory[0x00..0x04].copy_from_slice(&id.to_le_bytes());
+    memory[0x08..0x0C].copy_from_slice(&x.to_le_bytes());
+    memory[0x10..0x12].copy_from_slice(&health.to_le_bytes());
+    memory
+}
+
+#[test]
+fn test_diff_unchanged() {
+    let entity = Copy::<Entity>::new(entity_memory(1, 1.0, 100));
+    assert!(entity.diff(&entity.clone()).is_empty());
+}
+
+#[test]
+fn test_diff_fields() {
+    let old = Copy::<Entity>::new(entity_memory(1, 1.0, 100));
+    let new = Copy::<Entity>::new(entity_memory(1, 2.5, 90));
+
+    let diff = old.diff(&new);
+    assert_eq!(
+        diff.fields(),
+        &[
+            FieldChange {
+                name: "position",
+                offset: 0x08,
+                old: vec![0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x00],
+                new: vec![0x00, 0x00, 0x20, 0x40, 0x00, 0x00, 0x00, 0x00],
+            },
+            FieldChange {
+                name: "health",
+                offset: 0x10,
+                old: vec![0x64, 0x00],
+                new: vec![0x5A, 0x00],
+            },
+        ]
+    );
+    assert_eq!(
+        diff.to_string(),
+        concat!(
+            "position: Position @ 0x8 { x: [0x00] 1.0, y: [0x04] 0.0 } -> Position @ 0x8 { x: [0x00] 2.5, y: [0x04] 0.0 }\n",
+            "health: 100 -> 90\n",
+        )
+    );
+    assert!(diff.bytes().is_empty());
+}
+
+#[test]
+fn test_diff_unknown_bytes() {
+    let old_memory = entity_memory(1, 1.0, 100);
+    let mut new_memory = old_memory;
+    new_memory[0x04] = 0x01;
+    new_memory[0x05] = 0x02;
+    new_memory[0x18] = 0xFF;
+
+    let old = Reference::<Entity, _>::new(old_memory.as_slice(), 0x00);
+    let new = Reference::<Entity, _>::new(new_memory.as_slice(), 0x00);
+
+    let diff = old.diff(&new).unwrap();
+    assert!(diff.fields().is_empty());
+    assert_eq!(
+        diff.bytes(),
+        &[
+            ByteChange {
+                offset: 0x04,
+                old: vec![0x00, 0x00],
+                new: vec![0x01, 0x02],
+            },
+            ByteChange {
+                offset: 0x18,
+                old: vec![0x00],
+                new: vec![0xFF],
+            },
+        ]
+    );
+    assert_eq!(
+        diff.to_string(),
+        "+0x0004..0x0006: 00 00 -> 01 02\n+0x0018..0x0019: 00 -> FF\n"
+    );
+}
+
+#[test]
+fn test_diff_read_error() {
+    let memory = entity_memory(1, 1.0, 100);
+    let old = Reference::<Entity, _>::new(memory.as_slice(), 0x00);
+    let new = Reference::<Entity, _>::new(&memory[..0x10], 0x00);
+
+    assert!(old.diff(&new).is_err());
+}
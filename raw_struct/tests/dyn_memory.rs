@@ -0,0 +1,113 @@
+#![cfg(feature = "alloc")]
+
+use std::{
+    rc::Rc,
+    sync::Arc,
+};
+
+use raw_struct::{
+    builtins::Ptr64,
+    raw_struct,
+    views::{
+        SparseMemory,
+        UnmappedMemory,
+    },
+    DynMemoryView,
+    DynMemoryViewDereferenceable,
+    OutOfBoundsViolation,
+    Reference,
+};
+
+#[raw_struct(size = 0x10)]
+struct Node {
+    #[field(offset = 0x00)]
+    pub value: u32,
+
+    #[field(offset = 0x08)]
+    pub next: Ptr64<Node>,
+}
+
+fn read_value(memory: &dyn DynMemoryView, address: u64) -> Option<u32> {
+    Reference::<Node, _>::new(memory, address)
+        .read_field(Node::value)
+        .ok()
+}
+
+#[test]
+fn test_heterogeneous_views() {
+    let mut sparse = SparseMemory::new();
+    sparse.insert(0x1000, 0x22u32.to_le_bytes()).unwrap();
+
+    let memory = 0x11u32.to_le_bytes();
+    let views: Vec<Box<dyn DynMemoryView>> = vec![
+        Box::new(memory.as_slice()),
+        Box::new(Rc::new(sparse.clone())),
+        Box::new(Arc::new(sparse)),
+    ];
+
+    let values = views
+        .iter()
+        .map(|view| {
+            (
+                read_value(view.as_ref(), 0x00),
+                read_value(view.as_ref(), 0x1000),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        [(Some(0x11), None), (None, Some(0x22)), (None, Some(0x22))]
+    );
+}
+
+#[test]
+fn test_dyn_errors() {
+    let memory = [0u8; 0x04];
+    let memory: Box<dyn DynMemoryView + Send + Sync> = Box::new(memory.as_slice());
+
+    let object = Reference::<Node, _>::new(memory, 0x02);
+    let error = object
+        .read_field(Node::value)
+        .unwrap_err()
+        .into_access_error();
+
+    assert_eq!(
+        error.downcast_ref::<OutOfBoundsViolation>(),
+        Some(&OutOfBoundsViolation {
+            access_offset: 0x02,
+            access_len: 0x04,
+            src_len: 0x04,
+        })
+    );
+
+    let error: Box<dyn std::error::Error> = Box::new(error);
+    assert_eq!(
+        error.to_string(),
+        "memory outside of the struct has been accessed"
+    );
+}
+
+#[test]
+fn test_dyn_dereference() {
+    let mut memory = SparseMemory::new();
+    let mut node = [0u8; 0x10];
+    node[0x00..0x04].copy_from_slice(&0x11u32.to_le_bytes());
+    node[0x08..0x10].copy_from_slice(&0x2000u64.to_le_bytes());
+    memory.insert(0x1000, node).unwrap();
+
+    let memory: Rc<dyn DynMemoryViewDereferenceable> = Rc::new(memory);
+    let object = Reference::<Node, _>::new(memory, 0x1000);
+    assert_eq!(object.read_field(Node::value).unwrap(), 0x11);
+
+    let next = object.dereference_field(Node::next).unwrap();
+    let error = next
+        .read_field(Node::value)
+        .unwrap_err()
+        .into_access_error();
+    assert_eq!(
+        error
+            .downcast_ref::<UnmappedMemory>()
+            .map(|error| error.unmapped_address),
+        Some(0x2000)
+    );
+}
@@ -0,0 +1,101 @@
+#![cfg(all(feature = "std", any(unix, windows)))]
+
+use std::{
+    fs::{
+        self,
+        File,
+    },
+    path::PathBuf,
+};
+
+use raw_struct::{
+    raw_struct,
+    views::{
+        FileAccessError,
+        FileMemory,
+    },
+    Reference,
+};
+
+#[raw_struct(size = 0x10)]
+struct Dummy {
+    #[field(offset = 0x00)]
+    pub field_a: u32,
+
+    #[field(offset = 0x08)]
+    pub field_b: u64,
+}
+
+fn create_dump(name: &str) -> PathBuf {
+    let mut memory = [0u8; 0x20];
+    memory[0x10..0x14].copy_from_slice(&0xDEADBEEFu32.to_le_bytes());
+    memory[0x18..0x20].copy_from_slice(&0xB00B5B00B5u64.to_le_bytes());
+
+    let path = std::env::temp_dir().join(format!("raw_struct-{}-{}.bin", name, std::process::id()));
+    fs::write(&path, memory).unwrap();
+    path
+}
+
+#[test]
+fn test_file_memory() {
+    let path = create_dump("file_memory");
+    let memory = FileMemory::with_base_address(File::open(&path).unwrap(), 0x1000).unwrap();
+
+    let object = Reference::<Dummy, _>::new(&memory, 0x1010);
+    assert_eq!(object.read_field(Dummy::field_a).unwrap(), 0xDEADBEEF);
+    assert_eq!(object.read_field(Dummy::field_b).unwrap(), 0xB00B5B00B5);
+
+    let object = Reference::<Dummy, _>::new(&memory, 0x0FF0);
+    assert!(matches!(
+        object
+            .read_field(Dummy::field_a)
+            .unwrap_err()
+            .into_access_error(),
+        FileAccessError::OutOfRange {
+            address: 0x0FF0,
+            len: 4
+        }
+    ));
+
+    let object = Reference::<Dummy, _>::new(&memory, 0x1018);
+    assert!(matches!(
+        object
+            .read_field(Dummy::field_b)
+            .unwrap_err()
+            .into_access_error(),
+        FileAccessError::OutOfRange {
+            address: 0x1020,
+            len: 8
+        }
+    ));
+
+    drop(memory);
+    fs::remove_file(path).unwrap();
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_mapped_file_memory() {
+    use raw_struct::views::MappedFileMemory;
+
+    let path = create_dump("mapped_file_memory");
+    let file = File::open(&path).unwrap();
+    let memory = unsafe { MappedFileMemory::with_base_address(&file, 0x1000).unwrap() };
+
+    let object = Reference::<Dummy, _>::new(&memory, 0x1010);
+    assert_eq!(object.read_field(Dummy::field_a).unwrap(), 0xDEADBEEF);
+    assert_eq!(object.read_field(Dummy::field_b).unwrap(), 0xB00B5B00B5);
+
+    let object = Reference::<Dummy, _>::new(&memory, 0x1018);
+    assert!(matches!(
+        object
+            .read_field(Dummy::field_b)
+            .unwrap_err()
+            .into_access_error(),
+        FileAccessError::OutOfRange { .. }
+    ));
+
+    drop(memory);
+    drop(file);
+    fs::remove_file(path).unwrap();
+}
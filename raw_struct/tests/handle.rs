@@ -0,0 +1,95 @@
+#![cfg(feature = "alloc")]
+
+use raw_struct::{
+    builtins::{
+        Handle,
+        HandleResolver,
+    },
+    raw_struct,
+    views::MemoryImageBuilder,
+    FromMemoryView,
+    MemoryView,
+    Reference,
+};
+
+const ENTITY_LIST: u64 = 0x1000;
+
+#[raw_struct(size = 0x10)]
+struct Entity {
+    #[field(offset = 0x00)]
+    pub health: u32,
+
+    #[field(offset = 0x04)]
+    pub target: Handle<Entity, EntityList>,
+}
+
+/// Entity list entries consist of the entity pointer and the serial number
+#[raw_struct(size = 0x10)]
+struct EntityListEntry {
+    #[field(offset = 0x00)]
+    pub entity: u64,
+
+    #[field(offset = 0x08)]
+    pub serial: u32,
+}
+
+/// Handles consist of a 16 bit index and a 16 bit serial number
+struct EntityList;
+
+impl HandleResolver<Entity> for EntityList {
+    type Raw = u32;
+
+    fn resolve<M: MemoryView>(
+        handle: u32,
+        memory: M,
+    ) -> Result<Option<Reference<Entity, M>>, M::AccessError> {
+        let index = (handle & 0xFFFF) as u64;
+        let entry = Reference::<EntityListEntry, _>::new(&memory, ENTITY_LIST + index * 0x10);
+        let (entity, serial) =
+            entry.read_fields((EntityListEntry::entity, EntityListEntry::serial))?;
+
+        if entity == 0 || serial != handle >> 16 {
+            return Ok(None);
+        }
+
+        Ok(Some(Reference::new(memory, entity)))
+    }
+}
+
+#[test]
+fn test_handle_resolve() {
+    let mut builder = MemoryImageBuilder::with_base_address(0x10000);
+    let entity_a = builder.allocate::<Entity>().unwrap();
+    let entity_b = builder.allocate::<Entity>().unwrap();
+    let entry_a = builder.allocate_at::<EntityListEntry>(ENTITY_LIST).unwrap();
+    let entry_b = builder
+        .allocate_at::<EntityListEntry>(ENTITY_LIST + 0x10)
+        .unwrap();
+
+    builder.set_field(&entry_a, EntityListEntry::entity, entity_a.address());
+    builder.set_field(&entry_a, EntityListEntry::serial, 0x05);
+    builder.set_field(&entry_b, EntityListEntry::entity, entity_b.address());
+    builder.set_field(&entry_b, EntityListEntry::serial, 0x07);
+
+    builder.set_field(&entity_a, Entity::health, 100);
+    builder.set_field(&entity_a, Entity::target, Handle::from_raw(0x0007_0001));
+    builder.set_field(&entity_b, Entity::health, 50);
+    builder.set_field(&entity_b, Entity::target, Handle::from_raw(0x0004_0000));
+
+    let memory = builder.build();
+    let entity = entity_a.reference(&memory);
+
+    let target = entity.resolve_field(Entity::target).unwrap().unwrap();
+    assert_eq!(target.memory_address(), entity_b.address());
+    assert_eq!(target.read_field(Entity::health), Ok(50));
+
+    /* serial mismatch */
+    assert!(target.resolve_field(Entity::target).unwrap().is_none());
+
+    let handle = target.read_field(Entity::target).unwrap();
+    assert_eq!(handle.raw(), 0x0004_0000);
+
+    let handle = Handle::<Entity, EntityList>::from_raw(0x0005_0000);
+    let entity = handle.resolve(&memory).unwrap().unwrap();
+    assert_eq!(u32::read_object(&memory, entity.memory_address()), Ok(100));
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "alloc")]
+
+use std::cell::Cell;
+
+use raw_struct::{
+    raw_struct,
+    views::SparseMemory,
+    MemoryView,
+    OutOfBoundsViolation,
+    Reference,
+};
+
+#[raw_struct(size = 0x10)]
+struct Entity {
+    #[field(offset = 0x00)]
+    pub id: u32,
+
+    #[field(offset = 0x02)]
+    pub flags: u16,
+
+    #[field(offset = 0x08)]
+    pub values: [u8; 0x0C],
+}
+
+#[test]
+fn test_hex_dump_overlap_and_size() {
+    let mut memory = [0u8; 0x14];
+    memory[0x00..0x04].copy_from_slice(&0x0001_0007u32.to_le_bytes());
+
+    let entity = Reference::<Entity, _>::new(memory.as_slice(), 0x00);
+    assert_eq!(
+        entity.hex_dump().to_string(),
+        concat!(
+            "Entity @ 0x0 (0x10 bytes)\n",
+            "   +0x0000  07 00 01 00                                      id = 65543\n",
+            "!! +0x0002  01 00                                            flags = 1 (overlaps previous field)\n",
+            "?? +0x0004  00 00 00 00                                      <unknown>\n",
+            "!! +0x0008  00 00 00 00 00 00 00 00                          values = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0] (exceeds object size)\n",
+        )
+    );
+}
+
+#[test]
+fn test_hex_dump_unreadable() {
+    let memory = [0xAAu8; 0x0A];
+
+    let entity = Reference::<Entity, _>::new(memory.as_slice(), 0x00);
+    let dump = entity.hex_dump().to_string();
+    assert!(dump.contains("+0x0008  AA AA ?? ?? ?? ?? ?? ??  "));
+}
+
+#[test]
+fn test_hex_dump_partially_readable() {
+    /// Memory where only the page at 0x1000 is readable.
+    struct PageMemory {
+        reads: Cell<usize>,
+    }
+
+    impl MemoryView for PageMemory {
+        type AccessError = OutOfBoundsViolation;
+
+        fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+            self.reads.set(self.reads.get() + 1);
+            if offset < 0x1000 || offset + buffer.len() as u64 > 0x2000 {
+                return Err(OutOfBoundsViolation {
+                    access_offset: offset as usize,
+                    access_len: buffer.len(),
+                    src_len: 0x2000,
+                });
+            }
+
+            buffer.fill(0xAA);
+            Ok(())
+        }
+    }
+
+    #[raw_struct(size = 0x1800)]
+    struct Blob {
+        #[field(offset = 0x00)]
+        pub id: u32,
+    }
+
+    let memory = PageMemory {
+        reads: Cell::new(0),
+    };
+    let blob = Reference::<Blob, _>::new(&memory, 0x0800);
+    let dump = blob.hex_dump().to_string();
+    assert!(dump.contains("   +0x0000  ?? ?? ?? ??  "));
+    assert!(dump.contains("?? +0x07F4  ?? ?? ?? ?? ?? ?? ?? ?? ?? ?? ?? ?? AA AA AA AA\n"));
+    assert!(dump.contains("?? +0x17F4  AA AA AA AA AA AA AA AA AA AA AA AA\n"));
+    assert!(memory.reads.get() < 0x40, "{} reads", memory.reads.get());
+}
+
+#[test]
+fn test_hex_dump_chained_field() {
+    #[raw_struct(size = 0x08)]
+    struct Holder {
+        #[field(offset = 0x00, chain = [0x04])]
+        pub value: u32,
+    }
+
+    let memory = [0u8; 0x08];
+    let holder = Reference::<Holder, _>::new(memory.as_slice(), 0x00);
+    assert!(holder
+        .hex_dump()
+        .to_string()
+        .contains("   +0x0000  00 00 00 00 00 00 00 00  "));
+}
+
+#[test]
+fn test_hex_dump_colors() {
+    let memory = [0u8; 0x14];
+
+    let entity = Reference::<Entity, _>::new(memory.as_slice(), 0x00);
+    let dump = entity.hex_dump().colored().to_string();
+    assert!(dump.contains("\x1b[33m?? +0x0004"));
+    assert!(dump.contains("\x1b[31m!! +0x0002"));
+    assert!(!entity.hex_dump().to_string().contains('\x1b'));
+}
+
+#[test]
+fn test_hex_dump_distant_field() {
+    #[raw_struct(size = 0x04)]
+    struct Header {
+        #[field(offset = 0x00)]
+        pub magic: u32,
+
+        /// Far outside of the declared struct size
+        #[field(offset = 0xFFFF_FFFF_FFFF_FFF0)]
+        pub trailer: [u8; 0x20],
+    }
+
+    let mut memory = SparseMemory::new();
+    memory.insert(0x00, [0x11u8; 0x04]).unwrap();
+
+    let header = Reference::<Header, _>::new(&memory, 0x00);
+    let dump = header.hex_dump().to_string();
+    assert!(dump.contains("   +0x0000  11 11 11 11  "));
+    assert!(dump.contains("!! +0xFFFFFFFFFFFFFFF0  "));
+    assert!(dump.contains("(exceeds object size)"));
+}
@@ -0,0 +1,62 @@
+use raw_struct::{
+    builtins::Ptr64,
+    raw_struct,
+    views::LocalMemory,
+    Reference,
+};
+
+#[repr(C)]
+struct NativeNode {
+    value: u32,
+    next: *const NativeNode,
+}
+
+#[raw_struct(size = 0x10)]
+struct Node {
+    #[field(offset = 0x00)]
+    pub value: u32,
+
+    #[field(offset = 0x08)]
+    pub next: Ptr64<Node>,
+}
+
+#[test]
+fn test_local_memory() {
+    let node_b = Box::new(NativeNode {
+        value: 0x22,
+        next: std::ptr::null(),
+    });
+    let node_a = Box::new(NativeNode {
+        value: 0x11,
+        next: &*node_b,
+    });
+
+    let memory = unsafe { LocalMemory::new() };
+    let node = Reference::<Node, _>::new(memory, &*node_a as *const _ as u64);
+    assert_eq!(node.read_field(Node::value).unwrap(), 0x11);
+
+    let node = node.dereference_field(Node::next).unwrap();
+    assert_eq!(node.read_field(Node::value).unwrap(), 0x22);
+    assert!(node.read_field(Node::next).unwrap().is_null());
+}
+
+#[cfg(all(feature = "std", target_os = "linux"))]
+#[test]
+fn test_local_memory_probing() {
+    use raw_struct::views::LocalAccessError;
+
+    let value = Box::new(0xDEADBEEFu32);
+
+    let memory = unsafe { LocalMemory::with_probing() };
+    let object = Reference::<u32, _>::new(memory, &*value as *const _ as u64);
+    assert_eq!(object.read().unwrap(), 0xDEADBEEF);
+
+    let object = Reference::<u32, _>::new(memory, 0x10);
+    assert!(matches!(
+        object.read().unwrap_err().into_access_error(),
+        LocalAccessError::InvalidAddress {
+            address: 0x10,
+            len: 4
+        }
+    ));
+}
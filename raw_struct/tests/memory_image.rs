@@ -0,0 +1,132 @@
+#![cfg(feature = "alloc")]
+
+use raw_struct::{
+    builtins::Ptr64,
+    raw_struct,
+    views::{
+        AllocationError,
+        MemoryImageBuilder,
+        RegionOverlap,
+    },
+};
+
+#[raw_struct(size = 0x20)]
+struct Player {
+    #[field(offset = 0x00)]
+    pub health: u32,
+
+    #[field(offset = 0x08)]
+    pub inventory: Ptr64<Inventory>,
+}
+
+#[raw_struct(size = 0x10)]
+struct Inventory {
+    #[field(offset = 0x00)]
+    pub item_count: u64,
+
+    #[field(offset = 0x08)]
+    pub items: Ptr64<[u32]>,
+}
+
+#[test]
+fn test_memory_image() {
+    let mut builder = MemoryImageBuilder::with_base_address(0x7FF6_0000_0000);
+
+    let player = builder.allocate_at::<Player>(0x1_4000_1000).unwrap();
+    let inventory = builder.allocate::<Inventory>().unwrap();
+    let items = builder.allocate_array(&[0x11u32, 0x22, 0x33]).unwrap();
+    assert_eq!(inventory.address(), 0x7FF6_0000_0000);
+    assert_eq!(items.address(), 0x7FF6_0000_0010);
+
+    builder.set_field(&player, Player::health, 100);
+    builder.set_pointer(&player, Player::inventory, &inventory);
+    builder.set_field(&inventory, Inventory::item_count, 3);
+    builder.set_pointer(&inventory, Inventory::items, &items);
+
+    assert_eq!(
+        builder.allocate_at::<Inventory>(0x1_4000_1018).err(),
+        Some(AllocationError::RegionOverlap(RegionOverlap {
+            address: 0x1_4000_1018,
+            len: 0x10,
+            region_address: 0x1_4000_1000,
+            region_len: 0x20,
+        }))
+    );
+
+    let memory = builder.build();
+    let player = player.reference(&memory);
+    assert_eq!(player.read_field(Player::health), Ok(100));
+
+    let inventory = player.dereference_field(Player::inventory).unwrap();
+    assert_eq!(inventory.read_field(Inventory::item_count), Ok(3));
+
+    let items = inventory.dereference_field(Inventory::items).unwrap();
+    assert_eq!(items.read_element(2), Ok(0x33));
+}
+
+#[test]
+fn test_memory_image_skips_used_regions() {
+    let mut builder = MemoryImageBuilder::with_base_address(0x1000);
+    let fixed = builder.allocate_at::<Inventory>(0x1000).unwrap();
+    let auto = builder.allocate::<Inventory>().unwrap();
+
+    assert_eq!(fixed.address(), 0x1000);
+    assert_eq!(auto.address(), 0x1010);
+}
+
+#[test]
+#[should_panic]
+fn test_memory_image_field_out_of_bounds() {
+    #[raw_struct(size = 0x04)]
+    struct Tiny {
+        #[field(offset = 0x00)]
+        pub value: u64,
+    }
+
+    let mut builder = MemoryImageBuilder::new();
+    let tiny = builder.allocate::<Tiny>().unwrap();
+    builder.set_field(&tiny, Tiny::value, 0x00);
+}
+
+#[test]
+fn test_memory_image_pointer_array() {
+    let mut builder = MemoryImageBuilder::new();
+    let inventory = builder.allocate::<Inventory>().unwrap();
+    let pointers = builder
+        .allocate_array(&[[Ptr64::<Inventory>::from_address(inventory.address()); 2]])
+        .unwrap();
+
+    let memory = builder.build();
+    let pointers = pointers.reference(&memory);
+    let [first, second] = pointers.read_element(0).unwrap();
+    assert_eq!(first.address(), inventory.address());
+    assert_eq!(second.address(), inventory.address());
+}
+
+#[test]
+fn test_memory_image_address_space_exhausted() {
+    let mut builder = MemoryImageBuilder::with_base_address(u64::MAX - 0x08);
+    assert_eq!(
+        builder.allocate::<Inventory>().err(),
+        Some(AllocationError::AddressSpaceExhausted {
+            address: u64::MAX - 0x08,
+            len: 0x10,
+        })
+    );
+
+    let mut builder = MemoryImageBuilder::with_base_address(u64::MAX - 0x20);
+    assert_eq!(
+        builder
+            .allocate::<Inventory>()
+            .map(|allocation| allocation.address()),
+        Ok(u64::MAX - 0x1F)
+    );
+    assert!(builder.allocate::<Inventory>().is_err());
+    assert_eq!(
+        builder.allocate_at::<Inventory>(u64::MAX - 0x08).err(),
+        Some(AllocationError::AddressSpaceExhausted {
+            address: u64::MAX - 0x08,
+            len: 0x10,
+        })
+    );
+}
@@ -0,0 +1,67 @@
+use raw_struct::{
+    raw_struct,
+    views::{
+        MmioMemory,
+        UnsupportedMmioAccess,
+    },
+    Reference,
+};
+
+#[raw_struct(size = 0x10)]
+struct RegisterBlock {
+    #[field(offset = 0x00)]
+    pub control: u32,
+
+    #[field(offset = 0x04)]
+    pub status: u16,
+
+    #[field(offset = 0x06)]
+    pub flags: u8,
+
+    #[field(offset = 0x08)]
+    pub counter: u64,
+
+    /// Misaligned register
+    #[field(offset = 0x05)]
+    pub misaligned: u16,
+
+    #[field(offset = 0x00)]
+    pub raw: [u32; 4],
+}
+
+#[test]
+fn test_mmio_registers() {
+    let mut registers = Box::new([0u64; 2]);
+    registers[1] = 0xDEADBEEF;
+
+    let address = registers.as_mut_ptr() as u64;
+    let block = Reference::<RegisterBlock, _>::new(unsafe { MmioMemory::new() }, address);
+
+    block
+        .write_field(RegisterBlock::control, 0x11223344)
+        .unwrap();
+    block.write_field(RegisterBlock::status, 0x5566).unwrap();
+    block.write_field(RegisterBlock::flags, 0x77).unwrap();
+
+    assert_eq!(block.read_field(RegisterBlock::control), Ok(0x11223344));
+    assert_eq!(block.read_field(RegisterBlock::status), Ok(0x5566));
+    assert_eq!(block.read_field(RegisterBlock::flags), Ok(0x77));
+    assert_eq!(block.read_field(RegisterBlock::counter), Ok(0xDEADBEEF));
+
+    assert_eq!(
+        block.write_field(RegisterBlock::misaligned, 0x00),
+        Err(UnsupportedMmioAccess {
+            address: address + 0x05,
+            len: 2
+        })
+    );
+    assert_eq!(
+        block
+            .read_field(RegisterBlock::raw)
+            .unwrap_err()
+            .into_access_error(),
+        UnsupportedMmioAccess { address, len: 16 }
+    );
+
+    drop(registers);
+}
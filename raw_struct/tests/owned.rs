@@ -0,0 +1,216 @@
+use std::cell::Cell;
+
+use raw_struct::{
+    builtins::Ptr64,
+    raw_struct,
+    Copy,
+    FromMemoryView,
+    MemoryView,
+    OutOfBoundsViolation,
+    Reference,
+};
+
+#[raw_struct(
+    size = 0x08,
+    owned = "PositionData",
+    owned_derive = "Debug, Clone, Copy, PartialEq",
+    owned_write = true
+)]
+struct Position {
+    #[field(offset = 0x00)]
+    pub x: f32,
+
+    #[field(offset = 0x04)]
+    pub y: f32,
+}
+
+#[raw_struct(size = 0x20, owned = "EntityData")]
+struct Entity {
+    /// Unique id of the entity
+    #[field(offset = 0x00)]
+    pub id: u32,
+
+    #[field(offset = 0x08)]
+    pub position: Copy<Position>,
+
+    #[field(offset = 0x10)]
+    pub owner: Ptr64<Entity>,
+
+    #[field(offset = 0x10, chain = [0x00])]
+    pub owner_id: u32,
+}
+
+#[raw_struct(
+    memory = "[u8; 0x10]",
+    owned = "ItemData",
+    owned_derive = "Debug, PartialEq"
+)]
+struct Item<T: FromMemoryView<DecodeError = core::convert::Infallible> + 'static> {
+    #[field(offset = 0x00)]
+    pub value: T,
+}
+
+fn entity_memory() -> [u8; 0x20] {
+    let mut memory = [0u8; 0x20];
+    memory[0x00..0x04].copy_from_slice(&7u32.to_le_bytes());
+    memory[0x08..0x0C].copy_from_slice(&1.5f32.to_le_bytes());
+    memory[0x0C..0x10].copy_from_slice(&2.5f32.to_le_bytes());
+    memory[0x10..0x18].copy_from_slice(&0x1000u64.to_le_bytes());
+    memory
+}
+
+#[test]
+fn test_owned_from_reference() {
+    let memory = entity_memory();
+    let entity = Reference::<Entity, _>::new(memory.as_slice(), 0x00);
+
+    let data = EntityData::from_reference(&entity).unwrap();
+    assert_eq!(data.id, 7);
+    assert_eq!(data.owner.address(), 0x1000);
+    assert_eq!(
+        PositionData::from_copy(&data.position).unwrap(),
+        PositionData { x: 1.5, y: 2.5 }
+    );
+
+    let entity = Reference::<Entity, _>::new(&memory[..0x10], 0x00);
+    assert!(EntityData::from_reference(&entity).is_err());
+}
+
+#[test]
+fn test_owned_from_copy() {
+    let entity = Copy::<Entity>::new(entity_memory());
+    let data = EntityData::from_copy(&entity).unwrap();
+    assert_eq!(data.id, 7);
+
+    let item = Copy::<Item<u16>>::new([0x34, 0x12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    let data = ItemData::from_copy(&item).unwrap();
+    assert_eq!(data.value, 0x1234);
+}
+
+#[test]
+fn test_owned_write() {
+    let mut position = Copy::<Position>::new([0u8; 0x08]);
+    let data = PositionData { x: -1.0, y: 4.0 };
+    data.write_to(&mut position).unwrap();
+
+    assert_eq!(position.read_field(Position::x), Ok(-1.0));
+    assert_eq!(position.read_field(Position::y), Ok(4.0));
+    assert_eq!(PositionData::from_copy(&position), Ok(data));
+}
+
+#[raw_struct(size = 0x10, owned = "StatsData")]
+struct Stats {
+    #[field(offset = 0x00)]
+    pub alive: bool,
+
+    #[field(offset = 0x01)]
+    pub level: u8,
+
+    #[field(offset = 0x02)]
+    pub a: u8,
+
+    #[field(offset = 0x03)]
+    pub b: u8,
+
+    #[field(offset = 0x04)]
+    pub c: u8,
+
+    #[field(offset = 0x05)]
+    pub d: u8,
+
+    #[field(offset = 0x06)]
+    pub e: u8,
+
+    #[field(offset = 0x07)]
+    pub f: u8,
+
+    #[field(offset = 0x08)]
+    pub position: Copy<Position>,
+
+    #[field(offset = 0x0C)]
+    pub g: u16,
+
+    #[field(offset = 0x0E)]
+    pub h: u16,
+}
+
+struct BatchMemory<'a> {
+    memory: &'a [u8],
+    reads: Cell<usize>,
+    batches: Cell<usize>,
+}
+
+impl MemoryView for BatchMemory<'_> {
+    type AccessError = OutOfBoundsViolation;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        self.reads.set(self.reads.get() + 1);
+        self.memory.read_memory(offset, buffer)
+    }
+
+    fn read_memory_batch(
+        &self,
+        requests: &mut [(u64, &mut [u8])],
+    ) -> Result<(), Self::AccessError> {
+        self.batches.set(self.batches.get() + 1);
+        for (offset, buffer) in requests.iter_mut() {
+            self.memory.read_memory(*offset, buffer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_owned_batched_read() {
+    let mut bytes = [0u8; 0x10];
+    bytes[0x00] = 1;
+    bytes[0x01] = 42;
+    bytes[0x08..0x0C].copy_from_slice(&1.5f32.to_le_bytes());
+    bytes[0x0E..0x10].copy_from_slice(&7u16.to_le_bytes());
+
+    let memory = BatchMemory {
+        memory: &bytes[..],
+        reads: Cell::new(0),
+        batches: Cell::new(0),
+    };
+    let stats = StatsData::from_reference(&Reference::new(&memory, 0x00)).unwrap();
+    assert!(stats.alive);
+    assert_eq!(stats.level, 42);
+    assert_eq!(stats.position.read_field(Position::x), Ok(1.5));
+    assert_eq!(stats.h, 7);
+
+    /* eleven fields are read within two batches */
+    assert_eq!(memory.batches.get(), 2);
+    assert_eq!(memory.reads.get(), 0);
+}
+
+#[test]
+fn test_owned_write_out_of_bounds() {
+    #[raw_struct(memory = "[u8; 0x04]", owned = "HeaderData", owned_write = true)]
+    struct Header {
+        #[field(offset = 0x00)]
+        pub magic: u16,
+
+        #[field(offset = 0x02)]
+        pub length: u32,
+    }
+
+    let mut header = Copy::<Header>::new([0u8; 0x04]);
+    assert_eq!(header.set_field(Header::magic, 0x1234), Ok(()));
+    assert_eq!(
+        header.set_field(Header::length, 0x10),
+        Err(OutOfBoundsViolation {
+            access_offset: 0x02,
+            access_len: 0x04,
+            src_len: 0x04,
+        })
+    );
+
+    let data = HeaderData {
+        magic: 0x1234,
+        length: 0x10,
+    };
+    assert!(data.write_to(&mut header).is_err());
+    assert!(HeaderData::from_copy(&header).is_err());
+}
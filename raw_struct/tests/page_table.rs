@@ -0,0 +1,240 @@
+#![cfg(feature = "alloc")]
+
+use std::cell::Cell;
+
+use raw_struct::{
+    builtins::Ptr64,
+    raw_struct,
+    views::{
+        PageTableMemory,
+        PagingMode,
+        SparseMemory,
+        TranslationError,
+        UnmappedMemory,
+    },
+    MemoryView,
+    Reference,
+};
+
+const PRESENT: u64 = 0x01;
+
+#[raw_struct(size = 0x10)]
+struct Node {
+    #[field(offset = 0x00)]
+    pub value: u32,
+
+    #[field(offset = 0x08)]
+    pub next: Ptr64<Node>,
+}
+
+struct PhysicalMemory {
+    memory: SparseMemory,
+    reads: Cell<usize>,
+}
+
+impl PhysicalMemory {
+    fn new() -> Self {
+        Self {
+            memory: SparseMemory::new(),
+            reads: Cell::new(0),
+        }
+    }
+
+    fn set_entry(&mut self, table: u64, index: u64, value: u64) {
+        self.memory
+            .overwrite(table + index * 8, &value.to_le_bytes());
+    }
+}
+
+impl MemoryView for PhysicalMemory {
+    type AccessError = UnmappedMemory;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        self.reads.set(self.reads.get() + 1);
+        self.memory.read_memory(offset, buffer)
+    }
+}
+
+fn indices(address: u64) -> [u64; 5] {
+    [48, 39, 30, 21, 12].map(|shift| (address >> shift) & 0x1FF)
+}
+
+#[test]
+fn test_page_table_4k() {
+    let virtual_address = 0x7FF6_1234_5000u64;
+    let [_, pml4, pdpt, pd, pt] = indices(virtual_address);
+
+    let mut memory = PhysicalMemory::new();
+    memory.set_entry(0x1000, pml4, 0x2000 | PRESENT);
+    memory.set_entry(0x2000, pdpt, 0x3000 | PRESENT);
+    memory.set_entry(0x3000, pd, 0x4000 | PRESENT);
+    memory.set_entry(0x4000, pt, 0x8000 | PRESENT);
+    memory.set_entry(0x4000, pt + 1, 0x6000 | PRESENT);
+
+    /* node at the end of the first page, pointing into the second page */
+    let mut node = [0u8; 0x10];
+    node[0x00..0x04].copy_from_slice(&0x11u32.to_le_bytes());
+    node[0x08..0x10].copy_from_slice(&(virtual_address + 0x1008).to_le_bytes());
+    memory.memory.overwrite(0x8FF8, &node[..0x08]);
+    memory.memory.overwrite(0x6000, &node[0x08..]);
+    memory.memory.overwrite(0x6008, &0x22u32.to_le_bytes());
+
+    let memory = PageTableMemory::new(memory, 0x1000);
+    assert_eq!(memory.translate(virtual_address + 0x123), Ok(0x8123));
+
+    let object = Reference::<Node, _>::new(&memory, virtual_address + 0xFF8);
+    assert_eq!(object.read_field(Node::value), Ok(0x11));
+
+    let object = object.dereference_field(Node::next).unwrap();
+    assert_eq!(object.memory_address(), virtual_address + 0x1008);
+    assert_eq!(object.read_field(Node::value), Ok(0x22));
+
+    /* the translations are cached */
+    let reads = memory.memory().reads.get();
+    assert_eq!(object.read_field(Node::value), Ok(0x22));
+    assert_eq!(memory.memory().reads.get(), reads + 1);
+
+    memory.flush_tlb();
+    assert_eq!(object.read_field(Node::value), Ok(0x22));
+    assert_eq!(memory.memory().reads.get(), reads + 1 + 5);
+}
+
+#[test]
+fn test_page_table_large_pages() {
+    let address_2m = 0x0000_0040_1234_5678u64;
+    let address_1g = 0x0000_0080_1234_5678u64;
+
+    let mut memory = PhysicalMemory::new();
+    let [_, pml4, pdpt, pd, _] = indices(address_2m);
+    memory.set_entry(0x1000, pml4, 0x2000 | PRESENT);
+    memory.set_entry(0x2000, pdpt, 0x3000 | PRESENT);
+    memory.set_entry(0x3000, pd, 0x4060_0000 | 0x80 | PRESENT);
+
+    let [_, pml4, pdpt, _, _] = indices(address_1g);
+    memory.set_entry(0x1000, pml4, 0x5000 | PRESENT);
+    memory.set_entry(0x5000, pdpt, 0x1_C000_0000 | 0x80 | PRESENT);
+
+    let memory = PageTableMemory::new(memory, 0x1000);
+    assert_eq!(memory.translate(address_2m), Ok(0x4074_5678));
+    assert_eq!(memory.translate(address_1g), Ok(0x1_D234_5678));
+}
+
+#[test]
+fn test_page_table_not_present() {
+    let virtual_address = 0x7FF6_1234_5000u64;
+    let [_, pml4, pdpt, _, _] = indices(virtual_address);
+
+    let mut memory = PhysicalMemory::new();
+    memory.set_entry(0x1000, pml4, 0x2000 | PRESENT);
+    memory.set_entry(0x2000, pdpt, 0x3000);
+
+    let memory = PageTableMemory::new(memory, 0x1000);
+    let object = Reference::<Node, _>::new(&memory, virtual_address);
+    assert_eq!(
+        object
+            .read_field(Node::value)
+            .unwrap_err()
+            .into_access_error(),
+        TranslationError::NotPresent {
+            address: virtual_address,
+            level: 3
+        }
+    );
+
+    /* the PML4 table is not backed by any memory */
+    let memory = PageTableMemory::new(memory.memory().memory.clone(), 0x1_0000);
+    assert!(matches!(
+        memory.translate(virtual_address),
+        Err(TranslationError::MemoryAccess(UnmappedMemory { .. }))
+    ));
+}
+
+#[test]
+fn test_page_table_5_level() {
+    let virtual_address = 0x00AB_CDEF_1234_5000u64;
+    let [pml5, pml4, pdpt, pd, pt] = indices(virtual_address);
+
+    let mut memory = PhysicalMemory::new();
+    memory.set_entry(0x1000, pml5, 0x2000 | PRESENT);
+    memory.set_entry(0x2000, pml4, 0x3000 | PRESENT);
+    memory.set_entry(0x3000, pdpt, 0x4000 | PRESENT);
+    memory.set_entry(0x4000, pd, 0x5000 | PRESENT);
+    memory.set_entry(0x5000, pt, 0x9000 | PRESENT);
+
+    let memory = PageTableMemory::with_mode(memory, 0x1000, PagingMode::Level5);
+    assert_eq!(memory.translate(virtual_address + 0x80), Ok(0x9080));
+}
+
+#[test]
+fn test_page_table_non_canonical() {
+    let virtual_address = 0x7FF6_1234_5000u64;
+    let [_, pml4, pdpt, pd, pt] = indices(virtual_address);
+
+    let mut memory = PhysicalMemory::new();
+    memory.set_entry(0x1000, pml4, 0x2000 | PRESENT);
+    memory.set_entry(0x2000, pdpt, 0x3000 | PRESENT);
+    memory.set_entry(0x3000, pd, 0x4000 | PRESENT);
+    memory.set_entry(0x4000, pt, 0x8000 | PRESENT);
+    memory.set_entry(0x1000, 0x100, 0x00);
+
+    let memory = PageTableMemory::new(memory, 0x1000);
+    assert_eq!(memory.translate(virtual_address), Ok(0x8000));
+
+    /* would alias the mapping above if the upper bits were ignored */
+    assert_eq!(
+        memory.translate(0xFFFF_7FF6_1234_5000),
+        Err(TranslationError::NonCanonical {
+            address: 0xFFFF_7FF6_1234_5000
+        })
+    );
+    assert_eq!(
+        memory.translate(0x00AB_CDEF_1234_5000),
+        Err(TranslationError::NonCanonical {
+            address: 0x00AB_CDEF_1234_5000
+        })
+    );
+    assert!(matches!(
+        memory.translate(0xFFFF_8000_0000_0000),
+        Err(TranslationError::NotPresent { level: 4, .. })
+    ));
+
+    let memory =
+        PageTableMemory::with_mode(memory.memory().memory.clone(), 0x1000, PagingMode::Level5);
+    assert_eq!(
+        memory.translate(0xFE00_0000_0000_0000),
+        Err(TranslationError::NonCanonical {
+            address: 0xFE00_0000_0000_0000
+        })
+    );
+}
+
+#[test]
+fn test_page_table_address_overflow() {
+    let mut memory = PhysicalMemory::new();
+    memory.set_entry(0x1000, 0x1FF, 0x2000 | PRESENT);
+    memory.set_entry(0x2000, 0x1FF, 0x3000 | PRESENT);
+    memory.set_entry(0x3000, 0x1FF, 0x4000 | PRESENT);
+    memory.set_entry(0x4000, 0x1FF, 0x8000 | PRESENT);
+    memory.memory.overwrite(0x8FF8, &[0x11; 0x08]);
+
+    /* the first page is mapped as well, the read must not wrap around into it */
+    memory.set_entry(0x1000, 0x00, 0x5000 | PRESENT);
+    memory.set_entry(0x5000, 0x00, 0x6000 | PRESENT);
+    memory.set_entry(0x6000, 0x00, 0x7000 | PRESENT);
+    memory.set_entry(0x7000, 0x00, 0x9000 | PRESENT);
+    memory.memory.overwrite(0x9000, &[0x22; 0x08]);
+
+    let memory = PageTableMemory::new(memory, 0x1000);
+
+    let mut buffer = [0u8; 0x08];
+    assert_eq!(memory.read_memory(u64::MAX - 0x07, &mut buffer), Ok(()));
+    assert_eq!(buffer, [0x11; 0x08]);
+
+    assert_eq!(
+        memory.read_memory(u64::MAX - 0x03, &mut buffer),
+        Err(TranslationError::AddressOverflow {
+            address: u64::MAX - 0x03,
+            len: 0x08
+        })
+    );
+}
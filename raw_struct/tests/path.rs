@@ -0,0 +1,178 @@
+#![cfg(feature = "alloc")]
+
+use raw_struct::{
+    path::{
+        Layout,
+        LayoutRegistry,
+        LayoutType,
+        PathError,
+        PathEvaluator,
+        PathExpression,
+        PathSegment,
+        PrimitiveType,
+        Value,
+    },
+    views::SparseMemory,
+};
+
+fn create_registry() -> LayoutRegistry {
+    let mut registry = LayoutRegistry::new();
+    registry.register(
+        Layout::new("Player")
+            .with_size(0x10)
+            .with_field("health", 0x00, PrimitiveType::U32)
+            .with_field("alive", 0x04, PrimitiveType::Bool)
+            .with_field(
+                "inventory",
+                0x08,
+                LayoutType::pointer(LayoutType::structure("Inventory")),
+            ),
+    );
+    registry.register(Layout::new("Inventory").with_size(0x20).with_field(
+        "items",
+        0x00,
+        LayoutType::array(LayoutType::pointer(LayoutType::structure("Item")), 4),
+    ));
+    registry.register(
+        Layout::new("Item")
+            .with_size(0x08)
+            .with_field("id", 0x00, PrimitiveType::U32)
+            .with_field("weight", 0x04, PrimitiveType::F32),
+    );
+    registry
+}
+
+fn create_memory() -> SparseMemory {
+    let mut memory = SparseMemory::new();
+
+    let mut player = [0u8; 0x10];
+    player[0x00..0x04].copy_from_slice(&100u32.to_le_bytes());
+    player[0x04] = 1;
+    player[0x08..0x10].copy_from_slice(&0x2000u64.to_le_bytes());
+    memory.insert(0x1000, player).unwrap();
+
+    let mut inventory = [0u8; 0x20];
+    inventory[0x18..0x20].copy_from_slice(&0x3000u64.to_le_bytes());
+    memory.insert(0x2000, inventory).unwrap();
+
+    let mut item = [0u8; 0x08];
+    item[0x00..0x04].copy_from_slice(&42u32.to_le_bytes());
+    item[0x04..0x08].copy_from_slice(&1.5f32.to_le_bytes());
+    memory.insert(0x3000, item).unwrap();
+
+    memory
+}
+
+#[test]
+fn test_path_parse() {
+    let expression = PathExpression::parse("player->inventory.items[0x3]->id").unwrap();
+    assert_eq!(expression.root(), "player");
+    assert_eq!(
+        expression.segments(),
+        &[
+            PathSegment::PointerMember("inventory".into()),
+            PathSegment::Member("items".into()),
+            PathSegment::Index(3),
+            PathSegment::PointerMember("id".into()),
+        ]
+    );
+    assert_eq!(expression.to_string(), "player->inventory.items[3]->id");
+
+    assert_eq!(PathExpression::parse("player.").unwrap_err().position, 7);
+    assert_eq!(PathExpression::parse("player[x]").unwrap_err().position, 7);
+    assert_eq!(PathExpression::parse("player+1").unwrap_err().position, 6);
+}
+
+#[test]
+fn test_path_evaluate() {
+    let registry = create_registry();
+    let memory = create_memory();
+    let evaluator = PathEvaluator::new(&registry, &memory)
+        .with_root("player", Value::struct_pointer("Player", 0x1000));
+
+    assert_eq!(evaluator.evaluate("player->health"), Ok(Value::U32(100)));
+    assert_eq!(evaluator.evaluate("player->alive"), Ok(Value::Bool(true)));
+    assert_eq!(
+        evaluator.evaluate("player->inventory"),
+        Ok(Value::struct_pointer("Inventory", 0x2000))
+    );
+    assert_eq!(
+        evaluator.evaluate("player->inventory->items[3]->id"),
+        Ok(Value::U32(42))
+    );
+    assert_eq!(
+        evaluator.evaluate("player->inventory->items[3]->weight"),
+        Ok(Value::F32(1.5))
+    );
+
+    /* pointers can be indexed like arrays */
+    assert_eq!(evaluator.evaluate("player[0].health"), Ok(Value::U32(100)));
+}
+
+#[test]
+fn test_path_errors() {
+    let registry = create_registry();
+    let memory = create_memory();
+    let evaluator = PathEvaluator::new(&registry, &memory)
+        .with_root("player", Value::struct_pointer("Player", 0x1000));
+
+    assert_eq!(
+        evaluator.evaluate("enemy->health"),
+        Err(PathError::UnknownRoot("enemy".into()))
+    );
+    assert_eq!(
+        evaluator.evaluate("player->mana"),
+        Err(PathError::UnknownField {
+            layout: "Player".into(),
+            field: "mana".into()
+        })
+    );
+    assert_eq!(
+        evaluator.evaluate("player->inventory->items[4]"),
+        Err(PathError::IndexOutOfBounds { index: 4, len: 4 })
+    );
+    assert_eq!(
+        evaluator.evaluate("player->inventory->items[0]->id"),
+        Err(PathError::NullPointer(PathSegment::PointerMember(
+            "id".into()
+        )))
+    );
+    assert_eq!(
+        evaluator.evaluate("player.health"),
+        Err(PathError::InvalidSegment {
+            segment: PathSegment::Member("health".into()),
+            value: Value::struct_pointer("Player", 0x1000),
+        })
+    );
+    assert!(matches!(
+        evaluator.evaluate("player->inventory->items[2]"),
+        Ok(Value::Pointer { address: 0, .. })
+    ));
+}
+
+#[test]
+fn test_path_address_overflow() {
+    let registry = create_registry();
+    let memory = create_memory();
+    let evaluator = PathEvaluator::new(&registry, &memory)
+        .with_root("player", Value::struct_pointer("Player", 0x1000))
+        .with_root(
+            "last",
+            Value::struct_pointer("Player", 0xFFFF_FFFF_FFFF_FFFC),
+        );
+
+    assert_eq!(
+        evaluator.evaluate("player[0xFFFFFFFFFFFFFFFF]"),
+        Err(PathError::AddressOverflow { address: 0x1000 })
+    );
+    assert_eq!(
+        evaluator.evaluate("player[0x1000000000000000]"),
+        Err(PathError::AddressOverflow { address: 0x1000 })
+    );
+    assert_eq!(
+        evaluator.evaluate("last->inventory"),
+        Err(PathError::AddressOverflow {
+            address: 0xFFFF_FFFF_FFFF_FFFC
+        })
+    );
+}
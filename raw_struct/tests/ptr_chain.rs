@@ -0,0 +1,96 @@
+#![cfg(feature = "alloc")]
+
+use raw_struct::{
+    builtins::{
+        PtrChain,
+        PtrChainError,
+    },
+    raw_struct,
+    views::SparseMemory,
+    Reference,
+};
+
+#[raw_struct(size = 0x10)]
+struct Game {
+    #[field(offset = 0x00)]
+    pub tick: u32,
+
+    /// `*(*(self + 0x08) + 0x10) + 0x04`
+    #[field(offset = 0x08, chain = [0x10, 0x04])]
+    pub local_player_health: u32,
+}
+
+fn create_memory() -> SparseMemory {
+    let mut memory = SparseMemory::new();
+
+    let mut game = [0u8; 0x10];
+    game[0x00..0x04].copy_from_slice(&7u32.to_le_bytes());
+    game[0x08..0x10].copy_from_slice(&0x2000u64.to_le_bytes());
+    memory.insert(0x1000, game).unwrap();
+
+    let mut player_list = [0u8; 0x18];
+    player_list[0x10..0x18].copy_from_slice(&0x3000u64.to_le_bytes());
+    memory.insert(0x2000, player_list).unwrap();
+
+    let mut player = [0u8; 0x08];
+    player[0x04..0x08].copy_from_slice(&100u32.to_le_bytes());
+    memory.insert(0x3000, player).unwrap();
+
+    memory
+}
+
+#[test]
+fn test_chained_field() {
+    let memory = create_memory();
+
+    let game = Reference::<Game, _>::new(&memory, 0x1000);
+    assert_eq!(game.read_field(Game::tick), Ok(7));
+    assert_eq!(game.read_chain(Game::local_player_health), Ok(100));
+    assert_eq!(
+        game.reference_chain(Game::local_player_health)
+            .unwrap()
+            .memory_address(),
+        0x3004
+    );
+}
+
+#[test]
+fn test_chain_null_pointer() {
+    let mut memory = create_memory();
+    memory.overwrite(0x2010, &0u64.to_le_bytes());
+
+    let game = Reference::<Game, _>::new(&memory, 0x1000);
+    assert_eq!(
+        game.read_chain(Game::local_player_health),
+        Err(PtrChainError::NullPointer {
+            hop: 1,
+            address: 0x2010
+        })
+    );
+}
+
+#[test]
+fn test_chain_unmapped() {
+    let mut memory = create_memory();
+    memory.overwrite(0x2010, &0x4000u64.to_le_bytes());
+
+    let game = Reference::<Game, _>::new(&memory, 0x1000);
+    let Err(PtrChainError::MemoryAccess { hop, address, .. }) =
+        game.read_chain(Game::local_player_health)
+    else {
+        panic!("expected a memory access error");
+    };
+    assert_eq!((hop, address), (2, 0x4004));
+}
+
+#[test]
+fn test_ptr_chain() {
+    let memory = create_memory();
+
+    let chain = PtrChain::new(&[0x08, 0x10, 0x04]);
+    assert_eq!(chain.resolve(&memory, 0x1000), Ok(0x3004));
+    assert_eq!(chain.read::<u32, _>(&memory, 0x1000), Ok(100));
+
+    let chain = PtrChain::new(&[]);
+    assert_eq!(chain.resolve(&memory, 0x1000), Ok(0x1000));
+}
@@ -0,0 +1,83 @@
+use std::mem;
+
+use raw_struct::{
+    builtins::Ptr64,
+    Copy,
+    RawLayout,
+    Reference,
+    TypeKind,
+    Viewable,
+    ViewableField,
+    ViewableSized,
+};
+
+#[derive(Clone, Copy, RawLayout)]
+#[repr(C)]
+struct Position {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Clone, Copy, RawLayout)]
+#[repr(C)]
+struct Entity {
+    /// Unique id of the entity
+    pub id: u32,
+    pub position: Position,
+    pub flags: [u16; 2],
+    pub owner: Ptr64<Entity>,
+}
+
+#[test]
+fn test_layout_offsets() {
+    assert_eq!(Entity::id.offset(), 0x00);
+    assert_eq!(Entity::position.offset(), 0x04);
+    assert_eq!(Entity::flags.offset(), 0x0C);
+    assert_eq!(Entity::owner.offset(), 0x10);
+
+    assert_eq!(Entity::name(), "Entity");
+    assert_eq!(
+        Entity::fields()
+            .iter()
+            .map(|field| field.name())
+            .collect::<Vec<_>>(),
+        ["id", "position", "flags", "owner"]
+    );
+    assert_eq!(Entity::memory_size(), mem::size_of::<Entity>());
+}
+
+#[test]
+fn test_layout_type_info() {
+    assert!(matches!(
+        Entity::position.type_info().kind,
+        TypeKind::Struct { .. }
+    ));
+    assert_eq!(Entity::position.type_info().size, Some(0x08));
+    assert!(matches!(
+        Entity::owner.type_info().kind,
+        TypeKind::Pointer { .. }
+    ));
+}
+
+#[test]
+fn test_layout_read() {
+    let entity = Entity {
+        id: 7,
+        position: Position { x: 1.5, y: -2.0 },
+        flags: [1, 2],
+        owner: Ptr64::from_address(0x1000),
+    };
+
+    let copy = Copy::<Entity>::new(entity);
+    assert_eq!(copy.read_field(Entity::id), Ok(7));
+    assert_eq!(copy.read_field(Entity::flags), Ok([1, 2]));
+    assert_eq!(copy.read_field(Entity::owner).unwrap().address(), 0x1000);
+
+    let position = copy.read_field(Entity::position).unwrap();
+    assert_eq!(position.x, 1.5);
+    assert_eq!(position.y, -2.0);
+
+    let reference = Reference::<Entity, _>::new(copy.memory(), 0x00);
+    let position = reference.reference_field(Entity::position);
+    assert_eq!(position.read_field(Position::y), Ok(-2.0));
+}
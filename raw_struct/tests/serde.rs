@@ -0,0 +1,147 @@
+#![cfg(feature = "serde")]
+
+use raw_struct::{
+    builtins::Ptr64,
+    raw_struct,
+    views::MemoryImageBuilder,
+    Copy,
+    Reference,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_json::json;
+
+#[raw_struct(size = 0x08)]
+struct Position {
+    #[field(offset = 0x00)]
+    pub x: f32,
+
+    #[field(offset = 0x04)]
+    pub y: f32,
+}
+
+#[raw_struct(size = 0x10)]
+struct Entity {
+    #[field(offset = 0x00)]
+    pub id: u32,
+
+    #[field(offset = 0x08)]
+    pub position: Copy<Position>,
+}
+
+#[raw_struct(size = 0x28, inherits = "Entity")]
+struct Player {
+    #[field(offset = 0x10)]
+    pub ammo: [u16; 2],
+
+    #[field(offset = 0x18)]
+    pub target: Ptr64<Player>,
+
+    #[field(offset = 0x20)]
+    pub handle: Ptr64<()>,
+}
+
+#[test]
+fn test_serialize_copy() {
+    let mut memory = [0u8; 0x10];
+    memory[0x00..0x04].copy_from_slice(&7u32.to_le_bytes());
+    memory[0x08..0x0C].copy_from_slice(&1.5f32.to_le_bytes());
+
+    let entity = Copy::<Entity>::new(memory);
+    assert_eq!(
+        serde_json::to_value(&entity).unwrap(),
+        json!({ "id": 7, "position": { "x": 1.5, "y": 0.0 } })
+    );
+}
+
+#[test]
+fn test_serialize_follow_pointers() {
+    let mut builder = MemoryImageBuilder::with_base_address(0x1000);
+    let player_a = builder.allocate::<Player>().unwrap();
+    let player_b = builder.allocate::<Player>().unwrap();
+    builder.set_field(&player_a, Entity::id, 1);
+    builder.set_field(&player_a, Player::ammo, [10, 20]);
+    builder.set_pointer(&player_a, Player::target, &player_b);
+    builder.set_field(&player_b, Entity::id, 2);
+    builder.set_pointer(&player_b, Player::target, &player_a);
+    let memory = builder.build();
+
+    let player = Reference::<Player, _>::new(&memory, player_a.address());
+    assert_eq!(
+        serde_json::to_value(player.serialize_fields()).unwrap(),
+        json!({
+            "id": 1,
+            "position": { "x": 0.0, "y": 0.0 },
+            "ammo": [10, 20],
+            "target": 0x1030,
+            "handle": null,
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(player.serialize_fields().follow_pointers(1)).unwrap(),
+        json!({
+            "id": 1,
+            "position": { "x": 0.0, "y": 0.0 },
+            "ammo": [10, 20],
+            "target": {
+                "id": 2,
+                "position": { "x": 0.0, "y": 0.0 },
+                "ammo": [0, 0],
+                "target": 0x1000,
+                "handle": null,
+            },
+            "handle": null,
+        })
+    );
+}
+
+#[test]
+fn test_serialize_errors() {
+    let mut builder = MemoryImageBuilder::with_base_address(0x1000);
+    let player = builder.allocate::<Player>().unwrap();
+    builder.set_field(&player, Player::target, Ptr64::from_address(0x8000));
+    let memory = builder.build();
+
+    let player = Reference::<Player, _>::new(&memory, player.address());
+    let value = serde_json::to_value(player.serialize_fields().follow_pointers(1)).unwrap();
+    assert_eq!(value["target"]["id"], json!(null));
+    assert_eq!(value["target"]["ammo"], json!([null, null]));
+
+    let memory = [0u8; 0x04];
+    let entity = Reference::<Entity, _>::new(memory.as_slice(), 0x00);
+    assert_eq!(
+        serde_json::to_value(entity.serialize_fields()).unwrap(),
+        json!({ "id": 0, "position": { "x": null, "y": null } })
+    );
+}
+
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    #[serde(with = "raw_struct::serde::bytes")]
+    entity: Copy<Entity>,
+}
+
+#[test]
+fn test_bytes_round_trip() {
+    let mut memory = [0u8; 0x10];
+    memory[0x00..0x04].copy_from_slice(&7u32.to_le_bytes());
+
+    let snapshot = Snapshot {
+        entity: Copy::new(memory),
+    };
+    let json = serde_json::to_string(&snapshot).unwrap();
+    assert_eq!(json, r#"{"entity":[7,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0]}"#);
+
+    let snapshot = serde_json::from_str::<Snapshot>(&json).unwrap();
+    assert_eq!(snapshot.entity.read_field(Entity::id).unwrap(), 7);
+
+    let error = serde_json::from_str::<Snapshot>(r#"{"entity":[7,0,0,0]}"#)
+        .err()
+        .unwrap();
+    assert!(error
+        .to_string()
+        .contains("invalid length 4, expected 16 bytes"));
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "alloc")]
+
+use raw_struct::{
+    builtins::Ptr64,
+    raw_struct,
+    views::{
+        RegionOverlap,
+        SparseMemory,
+        UnmappedMemory,
+    },
+    MemoryView,
+    Reference,
+};
+
+#[raw_struct(size = 0x10)]
+struct Node {
+    #[field(offset = 0x00)]
+    pub value: u32,
+
+    #[field(offset = 0x08)]
+    pub next: Ptr64<Node>,
+}
+
+#[test]
+fn test_sparse_object_graph() {
+    let mut memory = SparseMemory::new();
+
+    let mut node_a = [0u8; 0x10];
+    node_a[0x00..0x04].copy_from_slice(&0x11u32.to_le_bytes());
+    node_a[0x08..0x10].copy_from_slice(&0x7FF6_1234_0000u64.to_le_bytes());
+    memory.insert(0x1_4000_0000, node_a).unwrap();
+
+    let mut node_b = [0u8; 0x10];
+    node_b[0x00..0x04].copy_from_slice(&0x22u32.to_le_bytes());
+    node_b[0x08..0x10].copy_from_slice(&0xDEAD_0000u64.to_le_bytes());
+    memory.insert(0x7FF6_1234_0000, node_b).unwrap();
+
+    let node = Reference::<Node, _>::new(&memory, 0x1_4000_0000);
+    assert_eq!(node.read_field(Node::value), Ok(0x11));
+
+    let node = node.dereference_field(Node::next).unwrap();
+    assert_eq!(node.memory_address(), 0x7FF6_1234_0000);
+    assert_eq!(node.read_field(Node::value), Ok(0x22));
+
+    let node = node.dereference_field(Node::next).unwrap();
+    assert_eq!(
+        node.read_field(Node::value)
+            .unwrap_err()
+            .into_access_error(),
+        UnmappedMemory {
+            access_address: 0xDEAD_0000,
+            access_len: 4,
+            unmapped_address: 0xDEAD_0000,
+        }
+    );
+}
+
+#[test]
+fn test_sparse_gaps() {
+    let mut memory = SparseMemory::new();
+    memory.insert(0x1000, [0x01u8; 0x10]).unwrap();
+    memory.insert(0x1010, [0x02u8; 0x10]).unwrap();
+    memory.insert(0x1030, [0x03u8; 0x10]).unwrap();
+
+    /* reads may span adjacent regions */
+    let mut buffer = [0u8; 0x04];
+    memory.read_memory(0x100E, &mut buffer).unwrap();
+    assert_eq!(buffer, [0x01, 0x01, 0x02, 0x02]);
+
+    let mut buffer = [0u8; 0x20];
+    assert_eq!(
+        memory.read_memory(0x1018, &mut buffer),
+        Err(UnmappedMemory {
+            access_address: 0x1018,
+            access_len: 0x20,
+            unmapped_address: 0x1020,
+        })
+    );
+
+    assert_eq!(
+        memory.insert(0x1028, [0u8; 0x10]),
+        Err(RegionOverlap {
+            address: 0x1028,
+            len: 0x10,
+            region_address: 0x1030,
+            region_len: 0x10,
+        })
+    );
+}
+
+#[test]
+fn test_sparse_address_space_end() {
+    let mut memory = SparseMemory::new();
+    memory.insert(u64::MAX - 0x10, [0x01u8; 0x10]).unwrap();
+    memory.insert(0x00, [0x02u8; 0x10]).unwrap();
+
+    let mut buffer = [0u8; 0x10];
+    memory.read_memory(u64::MAX - 0x10, &mut buffer).unwrap();
+    assert_eq!(buffer, [0x01; 0x10]);
+
+    /* reads must not wrap around into the start of the address space */
+    let mut buffer = [0u8; 0x20];
+    assert_eq!(
+        memory.read_memory(u64::MAX - 0x10, &mut buffer),
+        Err(UnmappedMemory {
+            access_address: u64::MAX - 0x10,
+            access_len: 0x20,
+            unmapped_address: u64::MAX,
+        })
+    );
+}
+
+#[test]
+fn test_sparse_overwrite() {
+    let mut memory = SparseMemory::new();
+    memory.insert(0x1000, [0x01u8; 0x10]).unwrap();
+    memory.insert(0x1020, [0x03u8; 0x10]).unwrap();
+    memory.overwrite(0x100C, &[0x02u8; 0x18]);
+
+    let regions = memory
+        .regions()
+        .map(|(address, region)| (address, region.len()))
+        .collect::<Vec<_>>();
+    assert_eq!(regions, [(0x1000, 0x30)]);
+
+    let mut buffer = [0u8; 0x30];
+    memory.read_memory(0x1000, &mut buffer).unwrap();
+    assert_eq!(buffer[0x0B..0x0D], [0x01, 0x02]);
+    assert_eq!(buffer[0x23..0x25], [0x02, 0x03]);
+}
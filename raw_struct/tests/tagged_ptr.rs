@@ -0,0 +1,100 @@
+#![cfg(feature = "alloc")]
+
+use raw_struct::{
+    builtins::{
+        CanonicalAddress48,
+        MaskBits,
+        Ptr64,
+        StripLowBits,
+        TopByteIgnore,
+    },
+    raw_struct,
+    views::SparseMemory,
+    Reference,
+};
+
+#[raw_struct(size = 0x18)]
+struct Node {
+    #[field(offset = 0x00)]
+    pub value: u32,
+
+    /// Pointer with a tag in the top byte
+    #[field(offset = 0x08)]
+    pub next: Ptr64<Node, TopByteIgnore>,
+
+    /// Pointer with flags stored in the lowest 3 bits
+    #[field(offset = 0x10)]
+    pub flagged: Ptr64<Node, StripLowBits<3>>,
+}
+
+#[test]
+fn test_pointer_policies() {
+    let ptr = Ptr64::<u8, TopByteIgnore>::from_address(0xB4FF_FF80_1234_5678);
+    assert_eq!(ptr.resolved_address(), 0xFFFF_FF80_1234_5678);
+    assert_eq!(ptr.tag(), 0xB400_0000_0000_0000);
+    assert_eq!(ptr.address(), 0xB4FF_FF80_1234_5678);
+
+    let ptr = Ptr64::<u8, TopByteIgnore>::from_address(0x2A00_7FF6_1234_5678);
+    assert_eq!(ptr.resolved_address(), 0x7FF6_1234_5678);
+    assert_eq!(ptr.tag() >> 56, 0x2A);
+
+    let ptr = Ptr64::<u8, CanonicalAddress48>::from_address(0x0000_8000_0000_0000);
+    assert_eq!(ptr.resolved_address(), 0xFFFF_8000_0000_0000);
+
+    let ptr = Ptr64::<u8, (StripLowBits<2>, MaskBits<0x0000_FFFF_FFFF_FFFF>)>::from_address(
+        0xFF00_1234_5678_9ABF,
+    );
+    assert_eq!(ptr.resolved_address(), 0x1234_5678_9ABC);
+    assert_eq!(ptr.tag(), 0xFF00_0000_0000_0003);
+
+    /* a null pointer carrying only tag bits is still null */
+    let ptr = Ptr64::<u8, StripLowBits<3>>::from_address(0x05);
+    assert!(!ptr.is_null());
+    assert!(ptr.is_resolved_null());
+    assert_eq!(ptr.read_value(&SparseMemory::new()), Ok(None));
+
+    let ptr = ptr.with_policy::<raw_struct::builtins::RawPointer>();
+    assert!(!ptr.is_resolved_null());
+
+    /* the raw pointer value is accessible in const contexts */
+    const PTR: Ptr64<u8> = Ptr64::from_address(0x1000);
+    const ADDRESS: u64 = PTR.address();
+    const IS_NULL: bool = PTR.is_null();
+    assert_eq!((ADDRESS, IS_NULL), (0x1000, false));
+}
+
+#[test]
+fn test_tagged_dereference() {
+    let mut node_a = [0u8; 0x18];
+    node_a[0x00..0x04].copy_from_slice(&0x11u32.to_le_bytes());
+    node_a[0x08..0x10].copy_from_slice(&0x3C00_0000_0000_2000u64.to_le_bytes());
+    node_a[0x10..0x18].copy_from_slice(&0x2007u64.to_le_bytes());
+
+    let mut node_b = [0u8; 0x18];
+    node_b[0x00..0x04].copy_from_slice(&0x22u32.to_le_bytes());
+
+    let mut memory = SparseMemory::new();
+    memory.insert(0x1000, node_a).unwrap();
+    memory.insert(0x2000, node_b).unwrap();
+
+    let node = Reference::<Node, _>::new(&memory, 0x1000);
+    let next = node.read_field(Node::next).unwrap();
+    assert_eq!(next.tag() >> 56, 0x3C);
+    assert_eq!(
+        next.copy_value(&memory)
+            .unwrap()
+            .unwrap()
+            .read_field(Node::value),
+        Ok(0x22)
+    );
+
+    let next = node.dereference_field(Node::next).unwrap();
+    assert_eq!(next.memory_address(), 0x2000);
+    assert_eq!(next.read_field(Node::value), Ok(0x22));
+
+    let flagged = node.read_field(Node::flagged).unwrap();
+    assert_eq!(flagged.tag(), 0x07);
+
+    let flagged = node.dereference_field(Node::flagged).unwrap();
+    assert_eq!(flagged.read_field(Node::value), Ok(0x22));
+}
@@ -0,0 +1,284 @@
+#![cfg(feature = "alloc")]
+
+use raw_struct::{
+    builtins::{
+        CompressedPtr32,
+        ConstCompression,
+        Ptr64,
+        StripLowBits,
+    },
+    path::{
+        LayoutRegistry,
+        LayoutType,
+        PathEvaluator,
+        Value,
+    },
+    raw_struct,
+    views::MemoryImageBuilder,
+    Copy,
+    Describable,
+    PrimitiveType,
+    TypeKind,
+    Viewable,
+    ViewableField,
+};
+
+#[raw_struct(size = 0x08)]
+struct Item {
+    #[field(offset = 0x00)]
+    pub id: u32,
+}
+
+#[derive(Clone, Copy)]
+struct Custom(#[allow(unused)] u16);
+impl raw_struct::CopyConstructable for Custom {}
+
+#[raw_struct(size = 0x40)]
+struct Inventory {
+    #[field(offset = 0x00)]
+    pub count: u32,
+
+    #[field(offset = 0x04)]
+    pub custom: Custom,
+
+    #[field(offset = 0x08)]
+    pub items: [Ptr64<Item>; 4],
+
+    #[field(offset = 0x28)]
+    pub first: Copy<Item>,
+
+    #[field(offset = 0x30)]
+    pub next: Ptr64<Inventory>,
+
+    #[field(offset = 0x38)]
+    pub data: Ptr64<[u8]>,
+}
+
+fn field(name: &str) -> &'static dyn ViewableField {
+    *Inventory::fields()
+        .iter()
+        .find(|field| field.name() == name)
+        .unwrap()
+}
+
+#[test]
+fn test_field_type_info() {
+    let count = field("count").type_info();
+    assert_eq!(count.name, "u32");
+    assert_eq!(count.size, Some(4));
+    assert!(matches!(
+        count.kind,
+        TypeKind::Primitive(PrimitiveType::U32)
+    ));
+
+    let custom = field("custom").type_info();
+    assert!(custom.name.ends_with("Custom"));
+    assert_eq!(custom.size, Some(2));
+    assert!(matches!(custom.kind, TypeKind::Opaque));
+
+    let items = field("items").type_info();
+    assert_eq!(items.size, Some(0x20));
+    let TypeKind::Array { element, len } = items.kind else {
+        panic!("expected an array");
+    };
+    assert_eq!(len, Some(4));
+    assert!(matches!(element().kind, TypeKind::Pointer { .. }));
+    assert_eq!(
+        items.fields().map(|fields| fields.len()),
+        None,
+        "array elements are pointers"
+    );
+
+    let first = field("first").type_info();
+    assert_eq!(first.name, "Item");
+    assert_eq!(first.size, Some(0x08));
+    assert_eq!(first.fields().map(|fields| fields[0].name()), Some("id"));
+
+    let next = field("next").type_info();
+    assert_eq!(next.size, Some(0x08));
+    assert_eq!(next.fields().map(|fields| fields.len()), Some(6));
+
+    let data = field("data").type_info();
+    let TypeKind::Pointer { pointee, .. } = data.kind else {
+        panic!("expected a pointer");
+    };
+    assert!(matches!(pointee().kind, TypeKind::Array { len: None, .. }));
+}
+
+#[test]
+fn test_generated_layouts() {
+    let mut registry = LayoutRegistry::new();
+    registry.register_viewable::<Inventory>();
+
+    let inventory = registry.layout("Inventory").unwrap();
+    assert_eq!(inventory.size(), Some(0x40));
+    assert!(inventory.field("custom").is_none());
+    assert_eq!(
+        inventory.field("items").map(|field| &field.field_type),
+        Some(&LayoutType::array(
+            LayoutType::pointer(LayoutType::structure("Item")),
+            4
+        ))
+    );
+    assert_eq!(
+        inventory.field("data").map(|field| &field.field_type),
+        Some(&LayoutType::pointer(PrimitiveType::U8.into()))
+    );
+    assert!(registry.layout("Item").is_some());
+
+    let mut builder = MemoryImageBuilder::new();
+    let item = builder.allocate::<Item>().unwrap();
+    builder.set_field(&item, Item::id, 42);
+    let inventory = builder.allocate::<Inventory>().unwrap();
+    builder.set_pointer(&inventory, Inventory::next, &inventory);
+    builder.set_field(&inventory, Inventory::items, [item.as_ptr(); 4]);
+    let memory = builder.build();
+
+    let evaluator = PathEvaluator::new(&registry, &memory).with_root(
+        "inventory",
+        Value::structure("Inventory", inventory.address()),
+    );
+    assert_eq!(
+        evaluator.evaluate("inventory.next->next->items[2]->id"),
+        Ok(Value::U32(42))
+    );
+}
+
+#[test]
+fn test_generated_pointer_layouts() {
+    type Heap = ConstCompression<0x1_0000_0000, 3>;
+
+    #[raw_struct(size = 0x10)]
+    struct Holder {
+        #[field(offset = 0x00)]
+        pub compressed: CompressedPtr32<Item, Heap>,
+
+        #[field(offset = 0x04)]
+        pub count: u32,
+
+        #[field(offset = 0x08)]
+        pub flagged: Ptr64<Item, StripLowBits<3>>,
+    }
+
+    let mut registry = LayoutRegistry::new();
+    registry.register_viewable::<Holder>();
+
+    let holder = registry.layout("Holder").unwrap();
+    assert!(matches!(
+        holder.field("compressed").map(|field| &field.field_type),
+        Some(LayoutType::Pointer { size: 4, .. })
+    ));
+    assert!(matches!(
+        holder.field("flagged").map(|field| &field.field_type),
+        Some(LayoutType::Pointer { size: 8, .. })
+    ));
+
+    let mut builder = MemoryImageBuilder::with_base_address(0x1_0000_1000);
+    let item = builder.allocate::<Item>().unwrap();
+    builder.set_field(&item, Item::id, 42);
+
+    let holder = builder.allocate::<Holder>().unwrap();
+    builder.set_field(
+        &holder,
+        Holder::compressed,
+        CompressedPtr32::from_value(((item.address() - 0x1_0000_0000) >> 3) as u32),
+    );
+    builder.set_field(&holder, Holder::count, 7);
+    builder.set_field(
+        &holder,
+        Holder::flagged,
+        Ptr64::from_address(item.address() | 0x05),
+    );
+    let memory = builder.build();
+
+    let evaluator = PathEvaluator::new(&registry, &memory)
+        .with_root("holder", Value::structure("Holder", holder.address()));
+    assert_eq!(
+        evaluator.evaluate("holder.compressed"),
+        Ok(Value::struct_pointer("Item", item.address()))
+    );
+    assert_eq!(
+        evaluator.evaluate("holder.compressed->id"),
+        Ok(Value::U32(42))
+    );
+    assert_eq!(evaluator.evaluate("holder.count"), Ok(Value::U32(7)));
+    assert_eq!(
+        evaluator.evaluate("holder.flagged"),
+        Ok(Value::struct_pointer("Item", item.address()))
+    );
+    assert_eq!(evaluator.evaluate("holder.flagged->id"), Ok(Value::U32(42)));
+}
+
+#[test]
+fn test_opaque_pointers() {
+    #[raw_struct(size = 0x10)]
+    struct Handles {
+        #[field(offset = 0x00)]
+        pub compressed: CompressedPtr32<Custom, ConstCompression<0x1_0000_0000, 3>>,
+
+        #[field(offset = 0x08)]
+        pub handle: Ptr64<()>,
+    }
+
+    let compressed = Handles::compressed.type_info();
+    assert_eq!(compressed.size, Some(4));
+    let TypeKind::Pointer { pointee, resolve } = compressed.kind else {
+        panic!("expected a pointer");
+    };
+    assert!(pointee().name.ends_with("Custom"));
+    assert!(matches!(pointee().kind, TypeKind::Opaque));
+    assert_eq!(resolve(0x10), 0x1_0000_0080);
+
+    let handle = Handles::handle.type_info();
+    assert_eq!(handle.size, Some(8));
+    let TypeKind::Pointer { pointee, .. } = handle.kind else {
+        panic!("expected a pointer");
+    };
+    assert_eq!(pointee().name, "()");
+    assert!(matches!(pointee().kind, TypeKind::Opaque));
+}
+
+#[test]
+fn test_undescribed_types() {
+    /// Base type which has been implemented by hand without type information.
+    struct HandWritten;
+
+    struct HandWrittenField;
+    impl ViewableField for HandWrittenField {
+        fn name(&self) -> &'static str {
+            "value"
+        }
+
+        fn offset(&self) -> u64 {
+            0x00
+        }
+    }
+
+    impl Viewable for HandWritten {
+        fn name() -> &'static str {
+            "HandWritten"
+        }
+
+        fn fields() -> &'static [&'static dyn ViewableField] {
+            &[&HandWrittenField]
+        }
+    }
+
+    #[raw_struct(size = 0x10, inherits = "HandWritten")]
+    struct Derived {
+        #[field(offset = 0x08)]
+        pub count: u32,
+    }
+
+    let field_info = HandWrittenField.type_info();
+    assert_eq!(field_info.size, None);
+    assert!(matches!(field_info.kind, TypeKind::Opaque));
+
+    let base = <Derived as Describable>::type_info().base().unwrap();
+    assert!(base.name.ends_with("HandWritten"));
+    assert!(matches!(base.kind, TypeKind::Opaque));
+
+    let mut registry = LayoutRegistry::new();
+    registry.register_viewable::<Derived>();
+    assert_eq!(registry.layout("Derived").unwrap().fields().len(), 1);
+}
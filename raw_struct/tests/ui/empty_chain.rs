@@ -0,0 +1,9 @@
+use raw_struct::raw_struct;
+
+#[raw_struct(size = 0x10)]
+struct Node {
+    #[field(offset = 0x08, chain = [])]
+    pub value: u32,
+}
+
+fn main() {}
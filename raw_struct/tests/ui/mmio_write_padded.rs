@@ -0,0 +1,16 @@
+use raw_struct::{
+    raw_struct,
+    views::MmioMemory,
+    Reference,
+};
+
+#[raw_struct(size = 0x04)]
+struct RegisterBlock {
+    #[field(offset = 0x00)]
+    pub pair: (u8, u16),
+}
+
+fn main() {
+    let block = Reference::<RegisterBlock, _>::new(unsafe { MmioMemory::new() }, 0x1000);
+    let _ = block.write_field(RegisterBlock::pair, (0x01, 0x0203));
+}
@@ -0,0 +1,12 @@
+use raw_struct::raw_struct;
+
+#[raw_struct(size = 0x08, owned = "FlagsData", owned_write = true)]
+struct Flags {
+    #[field(offset = 0x00)]
+    pub mask: u32,
+
+    #[field(offset = 0x04)]
+    pub enabled: bool,
+}
+
+fn main() {}
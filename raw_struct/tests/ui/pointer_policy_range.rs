@@ -0,0 +1,12 @@
+use raw_struct::builtins::{
+    PointerPolicy,
+    SignExtend,
+    StripLowBits,
+};
+
+const SIGN_EXTEND_MASK: u64 = SignExtend::<64>::ADDRESS_MASK;
+const STRIP_LOW_BITS_MASK: u64 = StripLowBits::<64>::ADDRESS_MASK;
+
+fn main() {
+    let _ = (SIGN_EXTEND_MASK, STRIP_LOW_BITS_MASK);
+}
@@ -0,0 +1,10 @@
+use raw_struct::RawLayout;
+
+#[derive(Clone, Copy, RawLayout)]
+#[repr(C)]
+struct Flags {
+    pub mask: u8,
+    pub enabled: bool,
+}
+
+fn main() {}
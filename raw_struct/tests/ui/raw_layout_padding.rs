@@ -0,0 +1,10 @@
+use raw_struct::RawLayout;
+
+#[derive(Clone, Copy, RawLayout)]
+#[repr(C)]
+struct Header {
+    pub kind: u8,
+    pub length: u32,
+}
+
+fn main() {}
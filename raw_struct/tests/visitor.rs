@@ -0,0 +1,253 @@
+#![cfg(feature = "alloc")]
+
+use raw_struct::{
+    builtins::Ptr64,
+    raw_struct,
+    views::{
+        MemoryImageBuilder,
+        SparseMemory,
+    },
+    ArrayValue,
+    Copy,
+    FieldVisitor,
+    MemoryView,
+    MemoryViewDereferenceable,
+    PointerValue,
+    Reference,
+    StructValue,
+    Viewable,
+    ViewableField,
+};
+
+#[raw_struct(size = 0x08)]
+struct Position {
+    #[field(offset = 0x00)]
+    pub x: f32,
+
+    #[field(offset = 0x04)]
+    pub y: f32,
+}
+
+#[raw_struct(size = 0x10)]
+struct Entity {
+    #[field(offset = 0x00)]
+    pub id: u32,
+
+    #[field(offset = 0x04)]
+    pub alive: u8,
+
+    #[field(offset = 0x08)]
+    pub position: Copy<Position>,
+}
+
+#[derive(Clone, Copy)]
+struct Opaque(#[allow(unused)] u64);
+impl raw_struct::CopyConstructable for Opaque {}
+
+#[raw_struct(size = 0x30, inherits = "Entity")]
+struct Player {
+    #[field(offset = 0x10)]
+    pub health: i16,
+
+    #[field(offset = 0x12)]
+    pub ammo: [u16; 3],
+
+    #[field(offset = 0x18)]
+    pub target: Ptr64<Entity>,
+
+    #[field(offset = 0x20)]
+    pub opaque: Opaque,
+}
+
+/// Collects all visited fields as `name=value` and follows pointers.
+#[derive(Default)]
+struct Collector {
+    prefix: String,
+    entries: Vec<String>,
+}
+
+impl Collector {
+    fn push(&mut self, field: &dyn ViewableField, value: impl ToString) {
+        self.entries.push(format!(
+            "{}{}={}",
+            self.prefix,
+            field.name(),
+            value.to_string()
+        ));
+    }
+
+    fn nested(&mut self, field: &dyn ViewableField, visit: impl FnOnce(&mut Self)) {
+        let prefix = format!("{}{}.", self.prefix, field.name());
+        let prefix = core::mem::replace(&mut self.prefix, prefix);
+        visit(self);
+        self.prefix = prefix;
+    }
+}
+
+impl<M: MemoryViewDereferenceable> FieldVisitor<M> for Collector
+where
+    M::AccessError: core::fmt::Debug,
+{
+    fn visit_field(&mut self, field: &dyn ViewableField) {
+        self.push(field, "?");
+    }
+
+    fn visit_u8(&mut self, field: &dyn ViewableField, value: Result<u8, M::AccessError>) {
+        self.push(field, value.unwrap());
+    }
+
+    fn visit_u16(&mut self, field: &dyn ViewableField, value: Result<u16, M::AccessError>) {
+        self.push(field, value.unwrap());
+    }
+
+    fn visit_i16(&mut self, field: &dyn ViewableField, value: Result<i16, M::AccessError>) {
+        self.push(field, value.unwrap());
+    }
+
+    fn visit_u32(&mut self, field: &dyn ViewableField, value: Result<u32, M::AccessError>) {
+        self.push(field, value.unwrap());
+    }
+
+    fn visit_f32(&mut self, field: &dyn ViewableField, value: Result<f32, M::AccessError>) {
+        self.push(field, value.unwrap());
+    }
+
+    fn visit_ptr(
+        &mut self,
+        field: &dyn ViewableField,
+        value: Result<PointerValue<'_, M>, M::AccessError>,
+    ) {
+        let value = value.unwrap();
+        assert_eq!(value.pointee().name, "Entity");
+        self.push(field, format!("0x{:X}", value.address()));
+
+        if let Some(target) = value.dereference().unwrap() {
+            self.nested(field, |this| target.visit_fields(this));
+        }
+    }
+
+    fn visit_struct(&mut self, field: &dyn ViewableField, value: StructValue<'_, M>) {
+        self.nested(field, |this| value.visit_fields(this));
+    }
+
+    fn visit_array(&mut self, _field: &dyn ViewableField, value: ArrayValue<'_, M>) {
+        for index in 0..value.len().unwrap() {
+            value.visit_element(index, self);
+        }
+    }
+}
+
+#[test]
+fn test_visit_fields() {
+    let mut builder = MemoryImageBuilder::new();
+    let target = builder.allocate::<Entity>().unwrap();
+    builder.set_field(&target, Entity::id, 2);
+
+    let player = builder.allocate::<Player>().unwrap();
+    builder.set_field(&player, Entity::id, 1);
+    builder.set_field(&player, Entity::alive, 1);
+    builder.set_field(&player, Player::health, -5);
+    builder.set_field(&player, Player::ammo, [10, 20, 30]);
+    builder.set_pointer(&player, Player::target, &target);
+    let mut memory = builder.build();
+
+    let mut position = [0u8; 0x08];
+    position[0x00..0x04].copy_from_slice(&1.5f32.to_le_bytes());
+    position[0x04..0x08].copy_from_slice(&(-2.0f32).to_le_bytes());
+    memory.write(player.address() + 0x08, &position).unwrap();
+
+    let reference = Reference::<Player, _>::new(&memory, player.address());
+    let mut collector = Collector::default();
+    Player::visit_fields(&reference, &mut collector);
+
+    assert_eq!(
+        collector.entries,
+        &[
+            "id=1".to_string(),
+            "alive=1".to_string(),
+            "position.x=1.5".to_string(),
+            "position.y=-2".to_string(),
+            "health=-5".to_string(),
+            "ammo=10".to_string(),
+            "ammo=20".to_string(),
+            "ammo=30".to_string(),
+            format!("target=0x{:X}", target.address()),
+            "target.id=2".to_string(),
+            "target.alive=0".to_string(),
+            "target.position.x=0".to_string(),
+            "target.position.y=0".to_string(),
+            "opaque=?".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_visit_errors() {
+    struct Errors(usize);
+
+    impl<M: MemoryView> FieldVisitor<M> for Errors {
+        fn visit_u32(&mut self, _field: &dyn ViewableField, value: Result<u32, M::AccessError>) {
+            self.0 += value.is_err() as usize;
+        }
+
+        fn visit_f32(&mut self, _field: &dyn ViewableField, value: Result<f32, M::AccessError>) {
+            self.0 += value.is_err() as usize;
+        }
+
+        fn visit_struct(&mut self, _field: &dyn ViewableField, value: StructValue<'_, M>) {
+            value.visit_fields(self);
+        }
+    }
+
+    /* only the entity id is within the memory */
+    let memory = [0u8; 0x04];
+    let reference = Reference::<Entity, _>::new(memory.as_slice(), 0x00);
+
+    let mut visitor = Errors(0);
+    Entity::visit_fields(&reference, &mut visitor);
+    assert_eq!(visitor.0, 2);
+
+    /* visitors can be used as trait objects */
+    let visitor: &mut dyn FieldVisitor<&[u8]> = &mut Errors(0);
+    Entity::visit_fields(&reference, visitor);
+}
+
+#[test]
+fn test_visit_invalid_elements() {
+    #[raw_struct(size = 0x08)]
+    struct Samples {
+        #[field(offset = 0x00)]
+        pub values: [u16; 4],
+    }
+
+    #[derive(Default)]
+    struct Elements {
+        errors: usize,
+        opaque: usize,
+    }
+
+    impl<M: MemoryView> FieldVisitor<M> for Elements {
+        fn visit_u16(&mut self, _field: &dyn ViewableField, value: Result<u16, M::AccessError>) {
+            self.errors += value.is_err() as usize;
+        }
+
+        fn visit_array(&mut self, _field: &dyn ViewableField, value: ArrayValue<'_, M>) {
+            for index in [0, 1, 2, 3, 4, usize::MAX] {
+                value.visit_element(index, self);
+            }
+        }
+
+        fn visit_opaque(&mut self, _field: &dyn ViewableField) {
+            self.opaque += 1;
+        }
+    }
+
+    /* the array exceeds the address space after the second element */
+    let memory = SparseMemory::new();
+    let reference = Reference::<Samples, _>::new(&memory, u64::MAX - 0x03);
+
+    let mut visitor = Elements::default();
+    Samples::visit_fields(&reference, &mut visitor);
+    assert_eq!(visitor.errors, 2);
+    assert_eq!(visitor.opaque, 4);
+}
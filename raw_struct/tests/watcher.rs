@@ -0,0 +1,215 @@
+#![cfg(feature = "std")]
+
+use std::{
+    cell::RefCell,
+    fmt,
+};
+
+use raw_struct::{
+    raw_struct,
+    Crossing,
+    FieldWatcher,
+    FromMemoryView,
+    MemoryDecodeError,
+    MemoryView,
+    OutOfBoundsViolation,
+    Reference,
+    WatchError,
+};
+
+#[raw_struct(size = 0x10)]
+struct Entity {
+    #[field(offset = 0x00)]
+    pub id: u32,
+}
+
+#[raw_struct(size = 0x10, inherits = "Entity")]
+struct Player {
+    #[field(offset = 0x04)]
+    pub health: i32,
+
+    #[field(offset = 0x08)]
+    pub position: f32,
+}
+
+struct SharedMemory(RefCell<Vec<u8>>);
+
+impl SharedMemory {
+    fn new(len: usize) -> Self {
+        Self(RefCell::new(vec![0u8; len]))
+    }
+
+    fn write(&self, offset: usize, value: &[u8]) {
+        self.0.borrow_mut()[offset..offset + value.len()].copy_from_slice(value);
+    }
+
+    fn truncate(&self, len: usize) {
+        self.0.borrow_mut().truncate(len);
+    }
+}
+
+impl MemoryView for SharedMemory {
+    type AccessError = OutOfBoundsViolation;
+
+    fn read_memory(&self, offset: u64, buffer: &mut [u8]) -> Result<(), Self::AccessError> {
+        self.0.borrow().as_slice().read_memory(offset, buffer)
+    }
+}
+
+#[test]
+fn test_watch_changes() {
+    let memory = SharedMemory::new(0x10);
+    let mut watcher = FieldWatcher::new(Reference::<Player, _>::new(&memory, 0x00))
+        .with_field(Entity::id)
+        .with_field(Player::health)
+        .with_field(Player::position);
+
+    assert!(watcher.poll().unwrap().is_empty());
+    assert!(watcher.poll().unwrap().is_empty());
+
+    memory.write(0x00, &7u32.to_le_bytes());
+    memory.write(0x08, &1.5f32.to_le_bytes());
+
+    let events = watcher.poll().unwrap();
+    assert_eq!(events.len(), 2);
+
+    assert!(events[0].is(Entity::id));
+    assert_eq!(events[0].field, "id");
+    assert_eq!(events[0].old_value::<u32>(), Some(&0));
+    assert_eq!(events[0].new_value::<u32>(), Some(&7));
+    assert_eq!(events[0].new_value::<i32>(), None);
+
+    assert!(events[1].is(Player::position));
+    assert_eq!(events[1].offset, 0x08);
+    assert_eq!(events[1].new_value::<f32>(), Some(&1.5));
+    assert_eq!(events[0].timestamp, events[1].timestamp);
+
+    assert!(watcher.poll().unwrap().is_empty());
+}
+
+#[test]
+fn test_watch_filters() {
+    let memory = SharedMemory::new(0x10);
+    memory.write(0x04, &100i32.to_le_bytes());
+
+    let mut watcher = FieldWatcher::new(Reference::<Player, _>::new(&memory, 0x00))
+        .with_filtered_field(Player::health, Crossing(50))
+        .with_filtered_field(Entity::id, |old: &u32, new: &u32| new > old);
+    watcher.poll().unwrap();
+
+    memory.write(0x04, &60i32.to_le_bytes());
+    assert!(watcher.poll().unwrap().is_empty());
+
+    memory.write(0x04, &50i32.to_le_bytes());
+    let events = watcher.poll().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].old_value::<i32>(), Some(&60));
+    assert_eq!(events[0].new_value::<i32>(), Some(&50));
+
+    memory.write(0x04, &20i32.to_le_bytes());
+    assert!(watcher.poll().unwrap().is_empty());
+
+    memory.write(0x04, &80i32.to_le_bytes());
+    assert_eq!(watcher.poll().unwrap().len(), 1);
+
+    memory.write(0x00, &5u32.to_le_bytes());
+    assert_eq!(watcher.poll().unwrap().len(), 1);
+
+    memory.write(0x00, &3u32.to_le_bytes());
+    assert!(watcher.poll().unwrap().is_empty());
+}
+
+#[test]
+fn test_watch_read_error() {
+    let memory = SharedMemory::new(0x10);
+    let mut watcher = FieldWatcher::new(Reference::<Player, _>::new(&memory, 0x00))
+        .with_field(Entity::id)
+        .with_field(Player::position);
+    watcher.poll().unwrap();
+
+    memory.write(0x00, &1u32.to_le_bytes());
+    memory.truncate(0x08);
+    assert!(watcher.poll().is_err());
+
+    memory.0.borrow_mut().resize(0x10, 0);
+    let events = watcher.poll().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].old_value::<u32>(), Some(&0));
+    assert_eq!(events[0].new_value::<u32>(), Some(&1));
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum State {
+    Idle,
+    Running,
+}
+
+#[derive(Debug)]
+struct InvalidState(u8);
+
+impl fmt::Display for InvalidState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid state {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidState {}
+
+impl FromMemoryView for State {
+    type DecodeError = InvalidState;
+
+    fn read_object<M: MemoryView>(
+        view: &M,
+        offset: u64,
+    ) -> Result<Self, MemoryDecodeError<M::AccessError, Self::DecodeError>> {
+        match u8::read_object(view, offset).map_err(|err| err.into_access_error()) {
+            Ok(0) => Ok(Self::Idle),
+            Ok(1) => Ok(Self::Running),
+            Ok(value) => Err(MemoryDecodeError::ValueDecode(InvalidState(value))),
+            Err(error) => Err(MemoryDecodeError::MemoryAccess(error)),
+        }
+    }
+}
+
+#[raw_struct(size = 0x08)]
+struct Task {
+    #[field(offset = 0x04)]
+    pub state: State,
+}
+
+#[test]
+fn test_watch_decoded_fields() {
+    let memory = SharedMemory::new(0x08);
+    let mut watcher =
+        FieldWatcher::new(Reference::<Task, _>::new(&memory, 0x00)).with_field(Task::state);
+    watcher.poll().unwrap();
+
+    memory.write(0x04, &[1]);
+    let events = watcher.poll().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].old_value::<State>(), Some(&State::Idle));
+    assert_eq!(events[0].new_value::<State>(), Some(&State::Running));
+
+    memory.write(0x04, &[7]);
+    let Err(WatchError::ValueDecode { field, error }) = watcher.poll() else {
+        panic!("expected a decode error");
+    };
+    assert_eq!(field, "state");
+    assert_eq!(error.to_string(), "invalid state 7");
+}
+
+#[test]
+fn test_watch_address_overflow() {
+    let memory = SharedMemory::new(0x08);
+    let mut watcher = FieldWatcher::new(Reference::<Task, _>::new(&memory, u64::MAX - 0x02))
+        .with_field(Task::state);
+
+    assert!(matches!(
+        watcher.poll(),
+        Err(WatchError::AddressOverflow {
+            field: "state",
+            offset: 0x04,
+            ..
+        })
+    ));
+}
@@ -0,0 +1,206 @@
+use raw_struct::{
+    raw_struct,
+    views::{
+        RebasedAccessError,
+        WindowAccessError,
+    },
+    MemoryView,
+    OutOfBoundsViolation,
+    Reference,
+};
+
+#[raw_struct(size = 0x08)]
+struct Dummy {
+    #[field(offset = 0x00)]
+    pub field_a: u32,
+
+    #[field(offset = 0x04)]
+    pub field_b: u32,
+
+    /// Not part of the declared struct size
+    #[field(offset = 0x08)]
+    pub field_c: u32,
+}
+
+fn create_memory() -> [u8; 0x20] {
+    let mut memory = [0u8; 0x20];
+    memory[0x10..0x14].copy_from_slice(&0x11u32.to_le_bytes());
+    memory[0x14..0x18].copy_from_slice(&0x22u32.to_le_bytes());
+    memory[0x18..0x1C].copy_from_slice(&0x33u32.to_le_bytes());
+    memory
+}
+
+#[test]
+fn test_window() {
+    let memory = create_memory();
+    let memory = memory.as_slice().window(0x10, 0x08);
+
+    let object = Reference::<Dummy, _>::new(&memory, 0x10);
+    assert_eq!(object.read_field(Dummy::field_b), Ok(0x22));
+    assert_eq!(
+        object
+            .read_field(Dummy::field_c)
+            .unwrap_err()
+            .into_access_error(),
+        WindowAccessError::OutOfWindow {
+            address: 0x18,
+            len: 4
+        }
+    );
+
+    let object = Reference::<Dummy, _>::new(&memory, 0x0C);
+    assert_eq!(
+        object
+            .read_field(Dummy::field_a)
+            .unwrap_err()
+            .into_access_error(),
+        WindowAccessError::OutOfWindow {
+            address: 0x0C,
+            len: 4
+        }
+    );
+}
+
+#[test]
+fn test_rebase() {
+    let memory = create_memory();
+    let memory = memory.as_slice().rebase(-0x1_4000_0000);
+
+    let object = Reference::<Dummy, _>::new(&memory, 0x1_4000_0010);
+    assert_eq!(object.read_field(Dummy::field_a), Ok(0x11));
+
+    let object = Reference::<Dummy, _>::new(&memory, 0x1_4000_001C);
+    assert_eq!(
+        object
+            .read_field(Dummy::field_b)
+            .unwrap_err()
+            .into_access_error(),
+        RebasedAccessError::MemoryAccess(OutOfBoundsViolation {
+            access_offset: 0x20,
+            access_len: 4,
+            src_len: 0x20
+        })
+    );
+}
+
+#[test]
+fn test_rebase_overflow() {
+    let memory = create_memory();
+    let mut buffer = [0u8; 4];
+
+    let rebased = memory.as_slice().rebase(-0x10);
+    assert_eq!(
+        rebased.read_memory(0x08, &mut buffer),
+        Err(RebasedAccessError::AddressOverflow {
+            address: 0x08,
+            delta: -0x10
+        })
+    );
+
+    let rebased = memory.as_slice().rebase(0x10);
+    assert_eq!(
+        rebased.read_memory(0xFFFF_FFFF_FFFF_FFF8, &mut buffer),
+        Err(RebasedAccessError::AddressOverflow {
+            address: 0xFFFF_FFFF_FFFF_FFF8,
+            delta: 0x10
+        })
+    );
+
+    let mut first = [0u8; 4];
+    let mut second = [0u8; 4];
+    assert_eq!(
+        rebased.read_memory_batch(&mut [(0x00, &mut first), (u64::MAX, &mut second)]),
+        Err(RebasedAccessError::AddressOverflow {
+            address: u64::MAX,
+            delta: 0x10
+        })
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_dereference_through_views() {
+    use raw_struct::{
+        builtins::Ptr64,
+        views::SparseMemory,
+    };
+
+    #[raw_struct(size = 0x10)]
+    struct Node {
+        #[field(offset = 0x00)]
+        pub value: u32,
+
+        #[field(offset = 0x08)]
+        pub next: Ptr64<Node>,
+    }
+
+    let mut node = [0u8; 0x20];
+    node[0x00..0x04].copy_from_slice(&0x11u32.to_le_bytes());
+    node[0x08..0x10].copy_from_slice(&0x1_4000_1010u64.to_le_bytes());
+    node[0x10..0x14].copy_from_slice(&0x22u32.to_le_bytes());
+
+    let mut memory = SparseMemory::new();
+    memory.insert(0x1000, node).unwrap();
+
+    let memory = memory.rebase(-0x1_4000_0000).window(0x1_4000_1000, 0x20);
+    let node = Reference::<Node, _>::new(&memory, 0x1_4000_1000);
+    assert_eq!(node.read_field(Node::value), Ok(0x11));
+
+    let next = node.dereference_field(Node::next).unwrap();
+    assert_eq!(next.memory_address(), 0x1_4000_1010);
+    assert_eq!(next.read_field(Node::value), Ok(0x22));
+}
+
+#[test]
+fn test_bounded_reference() {
+    let memory = create_memory();
+    let object = Reference::<Dummy, _>::new(memory.as_slice(), 0x10);
+    assert_eq!(object.read_field(Dummy::field_c), Ok(0x33));
+
+    let object = object.bounded();
+    assert_eq!(object.read_field(Dummy::field_a), Ok(0x11));
+    assert!(matches!(
+        object
+            .read_field(Dummy::field_c)
+            .unwrap_err()
+            .into_access_error(),
+        WindowAccessError::OutOfWindow { .. }
+    ));
+
+    let object = object.unbounded();
+    assert_eq!(object.read_field(Dummy::field_c), Ok(0x33));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_bounded_reference_dereference() {
+    use raw_struct::{
+        builtins::Ptr64,
+        views::SparseMemory,
+    };
+
+    #[raw_struct(size = 0x10)]
+    struct Node {
+        #[field(offset = 0x00)]
+        pub value: u32,
+
+        #[field(offset = 0x08)]
+        pub next: Ptr64<Node>,
+    }
+
+    let mut node = [0u8; 0x20];
+    node[0x00..0x04].copy_from_slice(&0x11u32.to_le_bytes());
+    node[0x08..0x10].copy_from_slice(&0x1010u64.to_le_bytes());
+    node[0x10..0x14].copy_from_slice(&0x22u32.to_le_bytes());
+
+    let mut memory = SparseMemory::new();
+    memory.insert(0x1000, node).unwrap();
+
+    let node = Reference::<Node, _>::new(&memory, 0x1000).bounded();
+    let next = node.dereference_field(Node::next).unwrap();
+    assert_eq!(next.memory_address(), 0x1010);
+    assert_eq!(next.read_field(Node::value), Ok(0x22));
+
+    let next = node.reference_field(Node::next).dereference().unwrap();
+    assert_eq!(next.read_field(Node::value), Ok(0x22));
+}
@@ -0,0 +1,131 @@
+use proc_macro2::TokenStream;
+use quote::{
+    quote,
+    quote_spanned,
+};
+use syn::{
+    spanned::Spanned,
+    Attribute,
+    Data,
+    DeriveInput,
+    Error,
+    Fields,
+    Meta,
+    NestedMeta,
+    Result,
+};
+
+use crate::derive_raw_struct::generate_type_info;
+
+/// Returns true if the type is attributed with `#[repr(C)]`.
+fn is_repr_c(attrs: &[Attribute]) -> Result<bool> {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        let Meta::List(list) = attr.parse_meta()? else {
+            continue;
+        };
+
+        let repr_c = list.nested.iter().any(
+            |nested| matches!(nested, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("C")),
+        );
+        if repr_c {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+pub fn raw_layout(input: DeriveInput) -> Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(input.span(), "only structs are supported"));
+    };
+
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            data.fields.span(),
+            "only named fields supported",
+        ));
+    };
+
+    if !is_repr_c(&input.attrs)? {
+        return Err(Error::new(
+            input.ident.span(),
+            "the struct layout must be defined with #[repr(C)]",
+        ));
+    }
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "generic structs are not supported",
+        ));
+    }
+
+    let struct_name = &input.ident;
+    let struct_name_str = format!("{}", struct_name);
+    let padding_message = format!("`{}` contains padding bytes", struct_name);
+
+    let mut field_constants = Vec::with_capacity(fields.named.len());
+    let mut field_names = Vec::with_capacity(fields.named.len());
+    let mut field_bounds = Vec::with_capacity(fields.named.len());
+    let mut field_types = Vec::with_capacity(fields.named.len());
+    for field in fields.named.iter() {
+        let Some(ident) = &field.ident else {
+            continue;
+        };
+
+        let ident_str = format!("{ident}");
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let attrs = field.attrs.iter().filter(|attr| attr.path.is_ident("doc"));
+        let type_info = generate_type_info(ty);
+
+        field_constants.push(quote! {
+            #(#attrs)*
+            #[allow(non_upper_case_globals)]
+            #vis const #ident: &::raw_struct::TypedViewableField<Self, #ty> = &::raw_struct::TypedViewableField::define(#ident_str, &|| {
+                ::core::mem::offset_of!(Self, #ident) as u64
+            }).with_type_info(#type_info);
+        });
+        field_names.push(quote! { Self:: #ident });
+
+        /* every bit pattern must be a valid field value and the fields must not contain padding */
+        field_bounds.push(quote_spanned! { ty.span() => #ty: ::raw_struct::PaddingFree });
+        field_types.push(ty);
+    }
+
+    Ok(quote! {
+        impl #struct_name {
+            #(#field_constants)*
+        }
+
+        impl ::raw_struct::Viewable for #struct_name {
+            fn name() -> &'static str {
+                #struct_name_str
+            }
+
+            fn fields() -> &'static [&'static dyn ::raw_struct::ViewableField] {
+                &[ #(#field_names,)* ]
+            }
+        }
+
+        impl ::raw_struct::Describable for #struct_name {
+            fn type_info() -> ::raw_struct::TypeInfo {
+                ::raw_struct::TypeInfo::structure::<Self>(Some(<Self as ::raw_struct::ViewableSized>::memory_size()))
+            }
+        }
+
+        const _: () = assert!(
+            ::core::mem::size_of::<#struct_name>() == 0 #(+ ::core::mem::size_of::<#field_types>())*,
+            #padding_message
+        );
+
+        impl ::raw_struct::CopyConstructable for #struct_name where #(#field_bounds,)* {}
+
+        unsafe impl ::raw_struct::PaddingFree for #struct_name where #(#field_bounds,)* {}
+
+        impl ::raw_struct::ViewableSized for #struct_name {
+            type Memory = Self;
+        }
+    })
+}
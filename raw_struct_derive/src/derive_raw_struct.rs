@@ -1,6 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::{
+    format_ident,
     quote,
+    quote_spanned,
     ToTokens,
 };
 use syn::{
@@ -13,61 +15,120 @@ use syn::{
     spanned::Spanned,
     Error,
     Expr,
+    ExprLit,
     Field,
     Fields,
     GenericParam,
+    Ident,
     ItemStruct,
     Lit,
     MetaNameValue,
     Path,
     Result,
     Token,
+    Type,
 };
 
+/// Number of fields of the largest `FieldBatch` tuple.
+const MAX_FIELD_BATCH: usize = 10;
+
 #[derive(Debug)]
 struct FieldArgs {
     // field(offset = 0x00)
     offset: TokenStream,
+
+    // field(offset = 0x00, chain = [0x10, 0x08])
+    chain: Option<Vec<Expr>>,
+}
+
+/// A single `key = value` pair of the field attribute.
+/// In contrast to [`MetaNameValue`] the value can be any expression.
+struct FieldArg {
+    key: Ident,
+    value: Expr,
+}
+
+impl Parse for FieldArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse()?;
+        Ok(Self { key, value })
+    }
 }
 
 impl Parse for FieldArgs {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let span = input.span();
         let fork = input.fork();
-        let Ok(vars) = fork.call(Punctuated::<MetaNameValue, Token![,]>::parse_terminated) else {
+        let Ok(vars) = fork.call(Punctuated::<FieldArg, Token![,]>::parse_terminated) else {
             /* the input is already the offset value */
             return Ok(Self {
                 offset: input.parse()?,
+                chain: None,
             });
         };
         input.advance_to(&fork);
 
         let mut offset = None;
+        let mut chain = None;
 
         for kv in &vars {
-            if kv.path.is_ident("offset") {
-                match &kv.lit {
-                    Lit::Int(value) => {
-                        offset = Some(value.base10_parse::<usize>()?.to_token_stream())
+            if kv.key == "offset" {
+                match &kv.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Int(value),
+                        ..
+                    }) => offset = Some(value.base10_parse::<usize>()?.to_token_stream()),
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(value),
+                        ..
+                    }) => offset = Some(value.parse::<Expr>()?.to_token_stream()),
+                    value => {
+                        return Err(Error::new(value.span(), "expected an interger or string"))
                     }
-                    Lit::Str(value) => offset = Some(value.parse::<Expr>()?.to_token_stream()),
-                    _ => return Err(Error::new(kv.lit.span(), "expected an interger or string")),
+                }
+            } else if kv.key == "chain" {
+                match &kv.value {
+                    Expr::Array(value) if value.elems.is_empty() => {
+                        return Err(Error::new(
+                            value.span(),
+                            "expected at least one chain offset",
+                        ))
+                    }
+                    Expr::Array(value) => chain = Some(value.elems.iter().cloned().collect()),
+                    value => return Err(Error::new(value.span(), "expected an array of offsets")),
                 }
             } else {
-                return Err(Error::new(kv.path.span(), "unknown attribute"));
+                return Err(Error::new(kv.key.span(), "unknown attribute"));
             }
         }
 
         Ok(Self {
-            offset: offset.ok_or(Error::new(vars.span(), "missing offset = \"...\""))?,
+            offset: offset.ok_or(Error::new(span, "missing offset = \"...\""))?,
+            chain,
         })
     }
 }
 
+#[derive(Debug)]
+struct OwnedArgs {
+    // owned = "MyStructData"
+    name: Ident,
+
+    // owned_derive = "Debug, Clone"
+    derives: Vec<Path>,
+
+    // owned_write = true
+    write: bool,
+}
+
 #[derive(Debug)]
 struct StructArgs {
     memory: Option<TokenStream>,
     inherits: Option<Path>,
     resolver: Path,
+    owned: Option<OwnedArgs>,
 }
 
 impl Parse for StructArgs {
@@ -79,6 +140,9 @@ impl Parse for StructArgs {
         let mut memory = None;
         let mut inherits = None;
         let mut resolver = None;
+        let mut owned = None;
+        let mut owned_derive = None;
+        let mut owned_write = None;
 
         for kv in &vars {
             if kv.path.is_ident("size") {
@@ -104,16 +168,69 @@ impl Parse for StructArgs {
                     Lit::Str(value) => resolver = Some(value.parse::<Path>()?),
                     _ => return Err(Error::new(kv.lit.span(), "expected a string")),
                 }
+            } else if kv.path.is_ident("owned") {
+                match &kv.lit {
+                    Lit::Str(value) => owned = Some(value.parse::<Ident>()?),
+                    _ => return Err(Error::new(kv.lit.span(), "expected a string")),
+                }
+            } else if kv.path.is_ident("owned_derive") {
+                match &kv.lit {
+                    Lit::Str(value) => {
+                        owned_derive = Some((
+                            kv.span(),
+                            value
+                                .parse_with(Punctuated::<Path, Token![,]>::parse_terminated)?
+                                .into_iter()
+                                .collect::<Vec<_>>(),
+                        ))
+                    }
+                    _ => return Err(Error::new(kv.lit.span(), "expected a string")),
+                }
+            } else if kv.path.is_ident("owned_write") {
+                match &kv.lit {
+                    Lit::Bool(value) => owned_write = Some((kv.span(), value.value)),
+                    _ => return Err(Error::new(kv.lit.span(), "expected a boolean")),
+                }
             } else {
                 return Err(Error::new(kv.path.span(), "unknown attribute"));
             }
         }
 
         let size = size.map(|size: TokenStream| quote::quote!([u8; #size]).to_token_stream());
+        let memory = memory.or(size);
+
+        let owned = match owned {
+            Some(name) => {
+                if let Some((span, true)) = owned_write {
+                    if memory.is_none() {
+                        return Err(Error::new(span, "owned_write requires the struct size"));
+                    }
+                }
+
+                Some(OwnedArgs {
+                    name,
+                    derives: owned_derive.map(|(_, derives)| derives).unwrap_or_default(),
+                    write: owned_write.map(|(_, write)| write).unwrap_or_default(),
+                })
+            }
+            None => {
+                let span = owned_derive
+                    .map(|(span, _)| span)
+                    .or(owned_write.map(|(span, _)| span));
+
+                if let Some(span) = span {
+                    return Err(Error::new(span, "missing owned = \"...\""));
+                }
+
+                None
+            }
+        };
+
         Ok(Self {
-            memory: memory.or(size),
+            memory,
             inherits,
             resolver: resolver.unwrap_or_else(|| syn::parse_quote! { ::core::convert::identity }),
+            owned,
         })
     }
 }
@@ -143,6 +260,18 @@ fn extract_struct_fields(fields: &Fields) -> Result<Vec<(FieldArgs, Field)>> {
     Ok(result)
 }
 
+/// Generate the type info function of a field type.
+pub(crate) fn generate_type_info(ty: &Type) -> TokenStream {
+    /* fields types which do not implement Describable fall back to an opaque type info */
+    quote! {
+        || {
+            #[allow(unused_imports)]
+            use ::raw_struct::{DescribeKnown as _, DescribeOpaque as _, DescribePointer as _};
+            (&&::raw_struct::TypeInfoProbe::<#ty>::new()).type_info()
+        }
+    }
+}
+
 fn generate_field_constants(resolver: &Path, fields: &[(FieldArgs, Field)]) -> Result<TokenStream> {
     let mut result = Vec::<TokenStream>::with_capacity(fields.len() * 2);
 
@@ -166,14 +295,26 @@ fn generate_field_constants(resolver: &Path, fields: &[(FieldArgs, Field)]) -> R
             })
             .collect::<Result<Vec<_>>>()?;
 
+        let type_info = generate_type_info(ty);
+
         let vis = &field.vis;
-        result.push(quote! {
-            #(#attrs)*
-            #[allow(non_upper_case_globals)]
-            #vis const #ident: &::raw_struct::TypedViewableField<Self, #ty> = &::raw_struct::TypedViewableField::define(#ident_str, &|| {
-                #resolver(#offset) as u64
+        if let Some(chain) = &field_args.chain {
+            result.push(quote! {
+                #(#attrs)*
+                #[allow(non_upper_case_globals)]
+                #vis const #ident: &::raw_struct::ChainedViewableField<Self, #ty> = &::raw_struct::ChainedViewableField::define(#ident_str, &|| {
+                    #resolver(#offset) as u64
+                }, &[ #(#chain as u64,)* ]).with_type_info(#type_info);
             });
-        });
+        } else {
+            result.push(quote! {
+                #(#attrs)*
+                #[allow(non_upper_case_globals)]
+                #vis const #ident: &::raw_struct::TypedViewableField<Self, #ty> = &::raw_struct::TypedViewableField::define(#ident_str, &|| {
+                    #resolver(#offset) as u64
+                }).with_type_info(#type_info);
+            });
+        }
     }
 
     Ok(quote! {
@@ -205,11 +346,10 @@ fn generate_struct_definition(args: &StructArgs, target: &ItemStruct) -> Result<
         })
         .collect::<Vec<_>>();
 
-    let inherits = if let Some(inherits) = &args.inherits {
-        Some(quote! { impl ::raw_struct::ViewableExtends< #inherits > for #name {} })
-    } else {
-        None
-    };
+    let inherits = args
+        .inherits
+        .as_ref()
+        .map(|inherits| quote! { impl ::raw_struct::ViewableExtends< #inherits > for #name {} });
 
     Ok(quote! {
         #(#attributes)*
@@ -221,6 +361,135 @@ fn generate_struct_definition(args: &StructArgs, target: &ItemStruct) -> Result<
     })
 }
 
+fn generate_owned_struct(
+    args: &StructArgs,
+    target: &ItemStruct,
+    fields: &[(FieldArgs, Field)],
+) -> Option<TokenStream> {
+    let owned = args.owned.as_ref()?;
+    let owned_name = &owned.name;
+
+    let vis = &target.vis;
+    let name = &target.ident;
+    let generics = &target.generics;
+    let (impl_generics, ty_generics, where_clause) = target.generics.split_for_impl();
+
+    /* chained fields can not be read from a copy and are therefore not part of the owned struct */
+    let fields = fields
+        .iter()
+        .filter(|(args, _)| args.chain.is_none())
+        .filter_map(|(_, field)| Some((field.ident.as_ref()?, field)))
+        .collect::<Vec<_>>();
+
+    let owned_fields = fields.iter().map(|(ident, field)| {
+        let ty = &field.ty;
+        let attrs = &field.attrs;
+        quote! {
+            #(#attrs)*
+            pub #ident: #ty,
+        }
+    });
+
+    let type_list = target
+        .generics
+        .params
+        .iter()
+        .filter_map(|ty| match ty {
+            GenericParam::Type(ty) => Some(ty.ident.clone().into_token_stream()),
+            GenericParam::Lifetime(lifetime) => Some(lifetime.lifetime.clone().into_token_stream()),
+            GenericParam::Const(_) => None,
+        })
+        .collect::<Vec<_>>();
+
+    let (generics_field, generics_value) = if type_list.is_empty() {
+        (None, None)
+    } else {
+        (
+            Some(quote! { _generics: core::marker::PhantomData<(#(#type_list,)*)>, }),
+            Some(quote! { _generics: core::marker::PhantomData, }),
+        )
+    };
+
+    let derives = (!owned.derives.is_empty()).then(|| {
+        let derives = &owned.derives;
+        quote! { #[derive(#(#derives),*)] }
+    });
+
+    let idents = fields.iter().map(|(ident, _)| *ident).collect::<Vec<_>>();
+
+    /* read the fields in batches of the largest supported FieldBatch */
+    let mut read_batches = Vec::new();
+    let mut field_values = Vec::new();
+    for (index, idents) in idents.chunks(MAX_FIELD_BATCH).enumerate() {
+        let batch = format_ident!("batch_{}", index);
+        read_batches.push(quote! {
+            let #batch = reference.read_fields((#(<#name #ty_generics>::#idents,)*))?;
+        });
+        field_values.extend(idents.iter().enumerate().map(|(index, ident)| {
+            let index = syn::Index::from(index);
+            quote! { #ident: #batch.#index }
+        }));
+    }
+
+    let from_copy = args.memory.as_ref().map(|_| {
+        quote! {
+            /// Decode all fields of the copy.
+            /// Fails if a field exceeds the struct size.
+            pub fn from_copy(
+                copy: &::raw_struct::Copy<#name #ty_generics>,
+            ) -> Result<Self, ::raw_struct::OutOfBoundsViolation> {
+                Self::from_reference(&**copy)
+            }
+        }
+    });
+
+    let write_to = owned.write.then(|| {
+        /* span the field writes to the field types to report fields which can not be written */
+        let set_fields = fields.iter().map(|(ident, field)| {
+            quote_spanned! { field.ty.span() =>
+                copy.set_field(<#name #ty_generics>::#ident, self.#ident)?;
+            }
+        });
+
+        quote! {
+            /// Write all fields into the copy.
+            /// Fails if a field exceeds the struct size.
+            pub fn write_to(
+                &self,
+                copy: &mut ::raw_struct::Copy<#name #ty_generics>,
+            ) -> Result<(), ::raw_struct::OutOfBoundsViolation> {
+                #(#set_fields)*
+                Ok(())
+            }
+        }
+    });
+
+    Some(quote! {
+        #derives
+        #vis struct #owned_name #generics #where_clause {
+            #(#owned_fields)*
+            #generics_field
+        }
+
+        impl #impl_generics #owned_name #ty_generics #where_clause {
+            /// Decode all fields of the referenced object.
+            pub fn from_reference<M: ::raw_struct::MemoryView>(
+                reference: &::raw_struct::Reference<#name #ty_generics, M>,
+            ) -> Result<Self, M::AccessError> {
+                #(#read_batches)*
+                Ok(Self {
+                    #(#field_values,)*
+                    #generics_value
+                })
+            }
+
+            #from_copy
+
+            #write_to
+        }
+    })
+}
+
 pub fn raw_struct(attr: TokenStream, input: TokenStream) -> Result<TokenStream> {
     let args = syn::parse2::<StructArgs>(attr)?;
     let target = syn::parse2::<ItemStruct>(input)?;
@@ -234,6 +503,7 @@ pub fn raw_struct(attr: TokenStream, input: TokenStream) -> Result<TokenStream>
     let (impl_generics, ty_generics, where_clause) = target.generics.split_for_impl();
 
     let struct_def = self::generate_struct_definition(&args, &target)?;
+    let owned_struct = self::generate_owned_struct(&args, &target, &fields);
     let field_names = target
         .fields
         .iter()
@@ -241,6 +511,17 @@ pub fn raw_struct(attr: TokenStream, input: TokenStream) -> Result<TokenStream>
         .map(|ident| quote! { Self:: #ident })
         .collect::<Vec<_>>();
 
+    let memory_size = if args.memory.is_some() {
+        quote! { Some(<Self as ::raw_struct::ViewableSized>::memory_size()) }
+    } else {
+        quote! { None }
+    };
+
+    let with_base = args.inherits.as_ref().map(|inherits| {
+        let type_info = generate_type_info(&syn::parse_quote!(#inherits));
+        quote! { .with_base(#type_info) }
+    });
+
     let sized_impl = args.memory.map(|memory| quote! {
         impl #impl_generics ::raw_struct::ViewableSized for #struct_name #ty_generics #where_clause {
             type Memory = #memory;
@@ -264,6 +545,14 @@ pub fn raw_struct(attr: TokenStream, input: TokenStream) -> Result<TokenStream>
             }
         }
 
+        impl #impl_generics ::raw_struct::Describable for #struct_name #ty_generics #where_clause {
+            fn type_info() -> ::raw_struct::TypeInfo {
+                ::raw_struct::TypeInfo::structure::<Self>(#memory_size) #with_base
+            }
+        }
+
         #sized_impl
+
+        #owned_struct
     })
 }
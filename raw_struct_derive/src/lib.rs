@@ -1,6 +1,7 @@
 use proc_macro::TokenStream;
 use syn::parse_macro_input;
 
+mod derive_raw_layout;
 mod derive_raw_struct;
 
 /// Marks a struct as a representation of a C-style struct with memory-mapped fields.
@@ -15,7 +16,21 @@ mod derive_raw_struct;
 ///   This allows a relaxiation of the `offset` value of the field as it may be anything. The function must return an u64.
 ///   By default the resolver is `core::convert::identity`.
 ///
+/// - `owned = "MyStructData"`
+///   Generates a plain struct with one public field per declared field and a `from_reference` constructor
+///   decoding all fields with batched reads (see `Reference::read_fields`). Sized structs additionally get a `from_copy` constructor.
+///   Chained and inherited fields are not part of the owned struct.
+///
+/// - `owned_derive = "Debug, Clone"`
+///   Traits to derive on the owned struct.
+///
+/// - `owned_write = true`
+///   Generates `write_to` on the owned struct which writes all fields into a `Copy`.
+///   Requires the struct size and all field types to be `PaddingFree`.
+///
 /// Each field within the struct must be annotated with the `#[field(...)]` attribute.
+/// The generated field constants carry the offset as well as runtime type information (`ViewableField::type_info`)
+/// of the field. Field types which do not implement `Describable` are described as opaque.
 ///
 /// # `#[field(...)]` Attributes:  
 /// - `offset = "<field offset>"` (required)  
@@ -24,6 +39,12 @@ mod derive_raw_struct;
 ///   **Note:** If a function call is used, the function will be executed each time the getter is invoked
 ///   to determine the field's offset.
 ///
+/// - `chain = [<offset>, ...]`  
+///   Declares the field as the end of a multi level pointer chain. At least one chain offset is required.
+///   The pointer at `offset` is followed, then every chain offset except the last one
+///   is added and dereferenced again. The last chain offset points to the value itself.
+///   Chained fields are read with `Reference::read_chain`.
+///
 /// # Example:
 /// ```ignore
 /// #[raw_struct(size = 0x10)]
@@ -36,6 +57,10 @@ mod derive_raw_struct;
 ///
 ///     #[field(offset = 0x08)]
 ///     pub field_c: [u8; 0x8],
+///
+///     /// `*(*(self + 0x08) + 0x10) + 0x04`
+///     #[field(offset = 0x08, chain = [0x10, 0x04])]
+///     pub field_d: u32,
 /// }
 /// ```
 #[proc_macro_attribute]
@@ -48,3 +73,32 @@ pub fn raw_struct(attr: TokenStream, input: TokenStream) -> TokenStream {
         .unwrap_or_else(|e| e.to_compile_error())
         .into()
 }
+
+/// Derives the raw_struct layout of an existing `#[repr(C)]` struct.
+///
+/// Field offsets are computed with `core::mem::offset_of!`. Like `#[raw_struct]` a field constant is generated
+/// for every field. The struct itself is used as the `ViewableSized::Memory` and therefore has to be `Copy`.
+///
+/// As the struct is constructed from raw memory, every field type has to be `PaddingFree`
+/// and the struct itself must not contain any padding bytes. Generic structs are not supported.
+///
+/// # Example:
+/// ```rust
+/// # use raw_struct::{RawLayout, ViewableField};
+/// #[derive(Clone, Copy, RawLayout)]
+/// #[repr(C)]
+/// struct MyStruct {
+///     pub field_a: u32,
+///     pub field_b: u32,
+/// }
+///
+/// assert_eq!(MyStruct::field_b.offset(), 0x04);
+/// ```
+#[proc_macro_derive(RawLayout)]
+pub fn raw_layout(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input);
+
+    derive_raw_layout::raw_layout(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}